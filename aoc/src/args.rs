@@ -0,0 +1,74 @@
+use std::collections::HashMap;
+
+pub struct Args {
+    positional: Vec<String>,
+    options: HashMap<String, String>,
+}
+
+impl Args {
+    // accepts `--key value`, `--key=value` and bare `--flag` options
+    pub fn parse(args: impl Iterator<Item = String>) -> Self {
+        let mut positional = Vec::new();
+        let mut options = HashMap::new();
+        let mut args = args.peekable();
+
+        while let Some(arg) = args.next() {
+            let Some(key) = arg.strip_prefix("--") else {
+                positional.push(arg);
+                continue;
+            };
+
+            if let Some((key, value)) = key.split_once('=') {
+                options.insert(key.to_string(), value.to_string());
+                continue;
+            }
+
+            let value = match args.peek() {
+                Some(next) if !next.starts_with("--") => args.next().unwrap(),
+                _ => "true".to_string(),
+            };
+
+            options.insert(key.to_string(), value);
+        }
+
+        Args {
+            positional,
+            options,
+        }
+    }
+
+    pub fn positional(&self, i: usize) -> Option<&str> {
+        self.positional.get(i).map(String::as_str)
+    }
+
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.options.get(key).map(String::as_str)
+    }
+
+    pub fn parse_positional<T: std::str::FromStr>(
+        &self,
+        i: usize,
+        name: &str,
+    ) -> Result<T, String> {
+        let value = self
+            .positional(i)
+            .ok_or(format!("missing argument <{}>", name))?;
+
+        value
+            .parse()
+            .map_err(|_| format!("invalid <{}>: {}", name, value))
+    }
+
+    pub fn parse_or<T: std::str::FromStr>(
+        &self,
+        key: &str,
+        default: T,
+    ) -> Result<T, String> {
+        match self.get(key) {
+            Some(value) => value
+                .parse()
+                .map_err(|_| format!("invalid --{}: {}", key, value)),
+            None => Ok(default),
+        }
+    }
+}
@@ -0,0 +1,261 @@
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+#[derive(Debug, PartialEq)]
+pub enum FetchSource {
+    Cache,
+    Remote,
+}
+
+pub struct Fetcher {
+    base_url: String,
+    session: Option<String>,
+    cache_dir: PathBuf,
+}
+
+impl Fetcher {
+    // `--base-url` wins over `AOC_BASE_URL`, which allows pointing the
+    // fetcher to a local stand-in server (see `aoc serve`)
+    pub fn from_env(base_url: Option<&str>) -> Self {
+        let base_url = base_url
+            .map(str::to_string)
+            .or(env::var("AOC_BASE_URL").ok())
+            .unwrap_or(DEFAULT_BASE_URL.to_string());
+
+        Fetcher {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: env::var("AOC_SESSION_COOKIE")
+                .ok()
+                .filter(|s| !s.is_empty()),
+            cache_dir: Fetcher::default_cache_dir(),
+        }
+    }
+
+    fn default_cache_dir() -> PathBuf {
+        if let Ok(dir) = env::var("AOC_CACHE_DIR") {
+            return PathBuf::from(dir);
+        }
+
+        let base = env::var("XDG_CACHE_HOME")
+            .map(PathBuf::from)
+            .or(env::var("HOME").map(|h| Path::new(&h).join(".cache")))
+            .unwrap_or(env::temp_dir());
+
+        base.join("aoc")
+    }
+
+    pub fn cache_dir(&self) -> &Path {
+        &self.cache_dir
+    }
+
+    pub fn cache_path(&self, year: u16, day: u8) -> PathBuf {
+        self.cache_dir
+            .join(year.to_string())
+            .join(format!("day{}", day))
+    }
+
+    pub fn fetch(
+        &self,
+        year: u16,
+        day: u8,
+        target: &Path,
+    ) -> Result<FetchSource, String> {
+        if target.exists() {
+            return Err(format!(
+                "{} already exists, refusing to overwrite it",
+                target.display()
+            ));
+        }
+
+        let cache_path = self.cache_path(year, day);
+
+        let (input, source) = match fs::read_to_string(&cache_path) {
+            Ok(input) => (input, FetchSource::Cache),
+            Err(_) => {
+                let input = self.download(year, day)?;
+                write_file(&cache_path, &input)?;
+
+                (input, FetchSource::Remote)
+            }
+        };
+
+        write_file(target, &input)?;
+
+        Ok(source)
+    }
+
+    fn download(&self, year: u16, day: u8) -> Result<String, String> {
+        let session = self
+            .session
+            .as_ref()
+            .ok_or("AOC_SESSION_COOKIE is not set")?;
+
+        let url = format!("{}/{}/day/{}/input", self.base_url, year, day);
+        // one file per puzzle, so concurrent fetches don't share it
+        let download_path = self.cache_path(year, day).with_extension("part");
+
+        fs::create_dir_all(download_path.parent().unwrap())
+            .map_err(|e| e.to_string())?;
+
+        let output = Command::new("curl")
+            .arg("--silent")
+            .arg("--show-error")
+            .args(["--write-out", "%{http_code}"])
+            .args(["--header", &format!("Cookie: session={}", session)])
+            .arg("--output")
+            .arg(&download_path)
+            .arg(&url)
+            .output()
+            .map_err(|e| format!("could not run curl: {}", e))?;
+
+        if !output.status.success() {
+            return Err(format!(
+                "could not download {}: {}",
+                url,
+                String::from_utf8_lossy(&output.stderr).trim()
+            ));
+        }
+
+        let status: u16 = String::from_utf8_lossy(&output.stdout)
+            .trim()
+            .parse()
+            .unwrap_or(0);
+
+        let body = fs::read_to_string(&download_path).unwrap_or_default();
+        let _ = fs::remove_file(&download_path);
+
+        check_input(status, &body)
+            .map_err(|e| format!("could not download {}: {}", url, e))?;
+
+        Ok(body)
+    }
+}
+
+// the server answers with HTML or plain text messages (e.g. when not logged
+// in or the puzzle is not unlocked yet), which must not end up as input
+pub fn check_input(status: u16, body: &str) -> Result<(), String> {
+    let first_line = body.lines().next().unwrap_or("").trim();
+
+    if status != 200 {
+        return Err(format!(
+            "server responded with {} ({})",
+            status, first_line
+        ));
+    }
+
+    if body.trim().is_empty() {
+        return Err("received an empty input".to_string());
+    }
+
+    if body.trim_start().starts_with('<') {
+        return Err("received an HTML page instead of an input".to_string());
+    }
+
+    let error_messages = [
+        "Please log in",
+        "Please don't repeatedly request",
+        "404 Not Found",
+    ];
+
+    if error_messages.iter().any(|m| body.contains(m)) {
+        return Err(format!("received an error page ({})", first_line));
+    }
+
+    Ok(())
+}
+
+fn write_file(path: &Path, content: &str) -> Result<(), String> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).map_err(|e| e.to_string())?;
+    }
+
+    fs::write(path, content)
+        .map_err(|e| format!("could not write {}: {}", path.display(), e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::serve::StandInServer;
+    use std::thread;
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = env::temp_dir().join(format!(
+            "aoc-test-{}-{}",
+            std::process::id(),
+            name
+        ));
+        let _ = fs::remove_dir_all(&dir);
+
+        dir
+    }
+
+    #[test]
+    fn check_input_accepts_input() {
+        assert_eq!(check_input(200, "3   4\n4   3\n"), Ok(()));
+    }
+
+    #[test]
+    fn check_input_rejects_error_pages() {
+        let html = "<!DOCTYPE html>\n<html lang=\"en-us\">";
+        let login = "Puzzle inputs differ by user.  Please log in to get \
+                     your puzzle input.\n";
+
+        assert!(check_input(200, html).is_err());
+        assert!(check_input(200, login).is_err());
+        assert!(check_input(200, "").is_err());
+        assert!(check_input(200, " \n").is_err());
+        assert!(check_input(404, "404 Not Found\n").is_err());
+        assert!(check_input(500, "1 2 3\n").is_err());
+    }
+
+    #[test]
+    fn fetch_from_stand_in_server() {
+        let dir = temp_dir("fetch");
+        let served = dir.join("served");
+        write_file(&served.join("2024").join("day5"), "1 2 3\n").unwrap();
+
+        let server = StandInServer::bind(0, &served).unwrap();
+        let base_url = server.url();
+        thread::spawn(move || server.serve());
+
+        let fetcher = Fetcher {
+            base_url,
+            session: Some("test".to_string()),
+            cache_dir: dir.join("cache"),
+        };
+
+        let first = dir.join("first");
+        let second = dir.join("second");
+
+        assert_eq!(
+            fetcher.fetch(2024, 5, &first),
+            Ok(FetchSource::Remote)
+        );
+        assert_eq!(fs::read_to_string(&first).unwrap(), "1 2 3\n");
+        assert!(fetcher.cache_path(2024, 5).exists());
+
+        // served from the cache even once the server no longer has it
+        fs::remove_file(served.join("2024").join("day5")).unwrap();
+        assert_eq!(
+            fetcher.fetch(2024, 5, &second),
+            Ok(FetchSource::Cache)
+        );
+        assert_eq!(fs::read_to_string(&second).unwrap(), "1 2 3\n");
+
+        fs::write(&first, "edited").unwrap();
+        assert!(fetcher.fetch(2024, 5, &first).is_err());
+        assert_eq!(fs::read_to_string(&first).unwrap(), "edited");
+
+        // not on the server and not cached
+        assert!(fetcher
+            .fetch(2024, 6, &dir.join("third"))
+            .is_err());
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
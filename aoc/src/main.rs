@@ -0,0 +1,176 @@
+mod args;
+mod fetch;
+mod puzzle;
+mod scaffold;
+mod serve;
+
+use std::env;
+use std::path::{Path, PathBuf};
+use std::process::{exit, Command};
+
+use aoc_core::solution::BENCH_PROBE;
+use args::Args;
+use fetch::{FetchSource, Fetcher};
+use puzzle::Puzzle;
+use serve::StandInServer;
+
+const USAGE: &str = "usage:
+    aoc new <day> [--year <year>] [--example-answer <part 1>]
+            [--example-answer2 <part 2>]
+    aoc run <day> [--year <year>]
+    aoc bench <day> [--year <year>] [--iterations <n>] [--warmup <n>]
+              [--history <file.csv|file.json>]
+    aoc fetch <day> [--year <year>] [--base-url <url>]
+    aoc serve [--port <port>] [--dir <dir>]";
+
+fn main() {
+    let args = Args::parse(env::args().skip(1));
+
+    let res = match args.positional(0) {
+        Some("new") => new(&args),
+        Some("run") => run(&args),
+        Some("bench") => bench(&args),
+        Some("fetch") => fetch(&args),
+        Some("serve") => serve(&args),
+        _ => Err(USAGE.to_string()),
+    };
+
+    if let Err(e) = res {
+        eprintln!("{}", e);
+        exit(1);
+    }
+}
+
+fn workspace_root() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .unwrap()
+        .to_path_buf()
+}
+
+fn day_dir(puzzle: &Puzzle) -> Result<PathBuf, String> {
+    let dir = workspace_root().join(puzzle.dir());
+
+    if !dir.is_dir() {
+        return Err(format!("{} does not exist", dir.display()));
+    }
+
+    Ok(dir)
+}
+
+fn new(args: &Args) -> Result<(), String> {
+    let puzzle = Puzzle::from_args(args)?;
+
+    let answers = [args.get("example-answer"), args.get("example-answer2")];
+
+    scaffold::create_day(&workspace_root(), &puzzle, answers)?;
+    println!("created {}", puzzle.dir().display());
+
+    // a missing session cookie should not fail the whole scaffolding
+    if let Err(e) = fetch_input(&puzzle, args.get("base-url")) {
+        eprintln!("could not fetch input: {}", e);
+    }
+
+    Ok(())
+}
+
+fn run(args: &Args) -> Result<(), String> {
+    let puzzle = Puzzle::from_args(args)?;
+
+    run_day(&puzzle, &[])
+}
+
+fn bench(args: &Args) -> Result<(), String> {
+    let puzzle = Puzzle::from_args(args)?;
+
+    if !runs_solution(&puzzle)? {
+        return Err(format!(
+            "{} does not run through aoc_core::solution::run and cannot be \
+             benchmarked",
+            puzzle.package()
+        ));
+    }
+
+    let iterations: usize = args.parse_or("iterations", 100)?;
+    let warmup: usize = args.parse_or("warmup", 5)?;
+    let history = match args.get("history") {
+        Some(file) => env::current_dir().unwrap().join(file),
+        None => workspace_root().join("bench").join("history.csv"),
+    };
+
+    run_day(
+        &puzzle,
+        &[
+            "--bench".to_string(),
+            iterations.to_string(),
+            "--warmup".to_string(),
+            warmup.to_string(),
+            "--history".to_string(),
+            history.to_string_lossy().to_string(),
+        ],
+    )
+}
+
+// only days started through `aoc_core::solution::run` understand the bench
+// arguments, any other day would read them as its own. Those answer the
+// probe, other days just run and their output is discarded
+fn runs_solution(puzzle: &Puzzle) -> Result<bool, String> {
+    let output = Command::new(env!("CARGO"))
+        .args(["run", "--release", "--quiet", "-p", &puzzle.package()])
+        .env(BENCH_PROBE, "1")
+        .current_dir(day_dir(puzzle)?)
+        .output()
+        .map_err(|e| format!("could not run cargo: {}", e))?;
+
+    Ok(String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .any(|l| l == BENCH_PROBE))
+}
+
+fn run_day(puzzle: &Puzzle, day_args: &[String]) -> Result<(), String> {
+    // days read their input relative to the crate directory
+    let status = Command::new(env!("CARGO"))
+        .args(["run", "--release", "-p", &puzzle.package(), "--"])
+        .args(day_args)
+        .current_dir(day_dir(puzzle)?)
+        .status()
+        .map_err(|e| format!("could not run cargo: {}", e))?;
+
+    if !status.success() {
+        return Err(format!("{} failed", puzzle.package()));
+    }
+
+    Ok(())
+}
+
+fn fetch(args: &Args) -> Result<(), String> {
+    let puzzle = Puzzle::from_args(args)?;
+
+    fetch_input(&puzzle, args.get("base-url"))
+}
+
+fn fetch_input(puzzle: &Puzzle, base_url: Option<&str>) -> Result<(), String> {
+    let fetcher = Fetcher::from_env(base_url);
+    let target = day_dir(puzzle)?.join("in").join("input");
+
+    match fetcher.fetch(puzzle.year, puzzle.day, &target)? {
+        FetchSource::Cache => println!("{} (from cache)", target.display()),
+        FetchSource::Remote => println!("{}", target.display()),
+    }
+
+    Ok(())
+}
+
+fn serve(args: &Args) -> Result<(), String> {
+    let port = args.parse_or("port", 8080)?;
+    let dir = match args.get("dir") {
+        Some(dir) => PathBuf::from(dir),
+        None => Fetcher::from_env(None).cache_dir().to_path_buf(),
+    };
+
+    let server = StandInServer::bind(port, &dir)?;
+    println!("serving {} on {}", dir.display(), server.url());
+    server.serve();
+
+    Ok(())
+}
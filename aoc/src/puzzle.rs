@@ -0,0 +1,37 @@
+use std::path::PathBuf;
+
+use crate::args::Args;
+
+pub const DEFAULT_YEAR: u16 = 2024;
+
+// days live in `year<year>/day<day>` as crate `year<year>_day<day>`
+#[derive(Debug, Clone, Copy)]
+pub struct Puzzle {
+    pub year: u16,
+    pub day: u8,
+}
+
+impl Puzzle {
+    pub fn from_args(args: &Args) -> Result<Self, String> {
+        let day = args.parse_positional(1, "day")?;
+        let year = args.parse_or("year", DEFAULT_YEAR)?;
+
+        if !(1..=25).contains(&day) {
+            return Err(format!("invalid <day>: {}", day));
+        }
+
+        Ok(Puzzle { year, day })
+    }
+
+    pub fn year_dir(&self) -> String {
+        format!("year{}", self.year)
+    }
+
+    pub fn dir(&self) -> PathBuf {
+        PathBuf::from(self.year_dir()).join(format!("day{}", self.day))
+    }
+
+    pub fn package(&self) -> String {
+        format!("year{}_day{}", self.year, self.day)
+    }
+}
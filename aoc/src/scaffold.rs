@@ -0,0 +1,109 @@
+use std::fs;
+use std::path::Path;
+
+use crate::puzzle::Puzzle;
+
+const CARGO_TEMPLATE: &str = include_str!("../templates/Cargo.toml.tpl");
+const MAIN_TEMPLATE: &str = include_str!("../templates/main.rs.tpl");
+
+// the example tests stay ignored until their answer is known
+fn main_source(answers: [Option<&str>; 2]) -> String {
+    let mut source = MAIN_TEMPLATE.to_string();
+
+    for (part, answer) in answers.iter().enumerate() {
+        let (answer, ignore) = match answer {
+            Some(answer) => (format!("{:?}", answer), ""),
+            None => (
+                "\"\"".to_string(),
+                "    #[ignore = \"fill in the example answer\"]\n",
+            ),
+        };
+
+        source = source
+            .replace(&format!("{{part{}_answer}}", part + 1), &answer)
+            .replace(&format!("{{part{}_ignore}}", part + 1), ignore);
+    }
+
+    source
+}
+
+pub fn create_day(
+    root: &Path,
+    puzzle: &Puzzle,
+    answers: [Option<&str>; 2],
+) -> Result<(), String> {
+    let dir = root.join(puzzle.dir());
+
+    if dir.exists() {
+        return Err(format!("{} already exists", dir.display()));
+    }
+
+    let files = [
+        (
+            "Cargo.toml",
+            CARGO_TEMPLATE.replace("{name}", &puzzle.package()),
+        ),
+        ("src/main.rs", main_source(answers)),
+        ("in/example", String::new()),
+    ];
+
+    for (file, content) in files {
+        let path = dir.join(file);
+
+        fs::create_dir_all(path.parent().unwrap())
+            .and_then(|_| fs::write(&path, content))
+            .map_err(|e| {
+                format!("could not write {}: {}", path.display(), e)
+            })?;
+    }
+
+    let year_glob = format!("{}/*", puzzle.year_dir());
+
+    register_member(&root.join("Cargo.toml"), &year_glob)
+}
+
+// adds the entry to the `members = [...]` list of the workspace manifest,
+// keeping the list sorted. Days are picked up by the `year<year>/*` globs,
+// so this is only needed for the first day of a new year
+fn register_member(manifest: &Path, name: &str) -> Result<(), String> {
+    let content = fs::read_to_string(manifest)
+        .map_err(|e| format!("could not read {}: {}", manifest.display(), e))?;
+
+    let start = content
+        .find("members = [")
+        .ok_or("no members list in workspace manifest")?;
+    let list_start = start + "members = [".len();
+    let list_end = list_start
+        + content[list_start..]
+            .find(']')
+            .ok_or("unterminated members list in workspace manifest")?;
+
+    let mut members: Vec<_> = content[list_start..list_end]
+        .split(',')
+        .map(|m| m.trim().trim_matches('"'))
+        .filter(|m| !m.is_empty())
+        .collect();
+
+    if members.contains(&name) {
+        return Ok(());
+    }
+
+    members.push(name);
+    members.sort();
+
+    let list = members
+        .iter()
+        .map(|m| format!("\"{}\"", m))
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    let updated = format!(
+        "{}{}{}",
+        &content[..list_start],
+        list,
+        &content[list_end..]
+    );
+
+    fs::write(manifest, updated)
+        .map_err(|e| format!("could not write {}: {}", manifest.display(), e))
+}
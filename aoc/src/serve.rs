@@ -0,0 +1,132 @@
+use std::fs;
+use std::io::{BufRead, BufReader, Write};
+use std::net::{TcpListener, TcpStream};
+use std::path::{Path, PathBuf};
+
+// minimal stand-in for `adventofcode.com`, serving inputs from a directory
+// with the same layout as the fetch cache (`<dir>/<year>/day<day>`)
+pub struct StandInServer {
+    listener: TcpListener,
+    dir: PathBuf,
+}
+
+impl StandInServer {
+    pub fn bind(port: u16, dir: &Path) -> Result<Self, String> {
+        let listener = TcpListener::bind(("127.0.0.1", port))
+            .map_err(|e| format!("could not bind port {}: {}", port, e))?;
+
+        Ok(StandInServer {
+            listener,
+            dir: dir.to_path_buf(),
+        })
+    }
+
+    pub fn url(&self) -> String {
+        let addr = self.listener.local_addr().unwrap();
+
+        format!("http://{}", addr)
+    }
+
+    pub fn serve(&self) {
+        for stream in self.listener.incoming().flatten() {
+            if let Err(e) = self.handle(stream) {
+                eprintln!("request failed: {}", e);
+            }
+        }
+    }
+
+    fn handle(&self, mut stream: TcpStream) -> std::io::Result<()> {
+        let mut reader = BufReader::new(&stream);
+        let mut request_line = String::new();
+        reader.read_line(&mut request_line)?;
+
+        let mut has_session = false;
+
+        loop {
+            let mut header = String::new();
+
+            if reader.read_line(&mut header)? == 0 || header.trim().is_empty() {
+                break;
+            }
+
+            let lower = header.to_ascii_lowercase();
+            has_session |=
+                lower.starts_with("cookie:") && lower.contains("session=");
+        }
+
+        let path = request_line
+            .split_whitespace()
+            .nth(1)
+            .unwrap_or("/");
+
+        let (status, body) = self.respond(path, has_session);
+        println!("{} {}", status, path);
+
+        write!(
+            stream,
+            "HTTP/1.1 {}\r\nContent-Type: text/plain\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+            status,
+            body.len(),
+            body
+        )
+    }
+
+    fn respond(&self, path: &str, has_session: bool) -> (&str, String) {
+        let parts: Vec<_> = path.trim_matches('/').split('/').collect();
+
+        // only numbers end up in the path, so requests cannot leave `dir`
+        let file = match parts.as_slice() {
+            [year, "day", day, "input"] => {
+                match (year.parse::<u16>(), day.parse::<u8>()) {
+                    (Ok(year), Ok(day)) => self
+                        .dir
+                        .join(year.to_string())
+                        .join(format!("day{}", day)),
+                    _ => return not_found(),
+                }
+            }
+            _ => return not_found(),
+        };
+
+        if !has_session {
+            return (
+                "400 Bad Request",
+                "Puzzle inputs differ by user.  Please log in to get your puzzle input.\n"
+                    .to_string(),
+            );
+        }
+
+        match fs::read_to_string(file) {
+            Ok(input) => ("200 OK", input),
+            Err(_) => not_found(),
+        }
+    }
+}
+
+fn not_found() -> (&'static str, String) {
+    ("404 Not Found", "404 Not Found\n".to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env;
+
+    #[test]
+    fn respond_stays_in_dir() {
+        let server = StandInServer::bind(0, &env::temp_dir()).unwrap();
+
+        for path in [
+            "/../day/x/input",
+            "/2024/day/..%2F1/input",
+            "/x/day/1/input",
+        ] {
+            assert_eq!(server.respond(path, true).0, "404 Not Found");
+        }
+
+        assert_eq!(
+            server.respond("/2024/day/1/input", false).0,
+            "400 Bad Request"
+        );
+    }
+}
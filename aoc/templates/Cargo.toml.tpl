@@ -1,7 +1,7 @@
 [package]
-name = "day1"
+name = "{name}"
 version = "0.1.0"
 edition = "2021"
 
 [dependencies]
-aoc_core = { path = "../aoc_core" }
\ No newline at end of file
+aoc_core = { path = "../../aoc_core" }
@@ -0,0 +1,46 @@
+use aoc_core::solution::{run, Solution};
+
+struct Day;
+
+impl Solution for Day {
+    type Input = Vec<String>;
+
+    fn parse(input: &str) -> Self::Input {
+        input.lines().map(str::to_string).collect()
+    }
+
+    fn part1(input: &Self::Input) -> String {
+        input.len().to_string()
+    }
+
+    fn part2(input: &Self::Input) -> String {
+        input.len().to_string()
+    }
+}
+
+fn main() {
+    run::<Day>("in/input");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::read;
+
+    const EXAMPLE_PART1: &str = {part1_answer};
+    const EXAMPLE_PART2: &str = {part2_answer};
+
+    #[test]
+{part1_ignore}    fn part1_example() {
+        let input = Day::parse(&read("in/example"));
+
+        assert_eq!(Day::part1(&input), EXAMPLE_PART1);
+    }
+
+    #[test]
+{part2_ignore}    fn part2_example() {
+        let input = Day::parse(&read("in/example"));
+
+        assert_eq!(Day::part2(&input), EXAMPLE_PART2);
+    }
+}
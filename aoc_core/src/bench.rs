@@ -0,0 +1,206 @@
+use std::fmt;
+use std::fs::{self, OpenOptions};
+use std::hint::black_box;
+use std::io::{self, Write};
+use std::path::Path;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use crate::measure;
+use crate::solution::Solution;
+
+const CSV_HEADER: &str =
+    "timestamp,name,stage,iterations,median_ns,min_ns,stddev_ns";
+
+#[derive(Debug, Clone, Copy)]
+pub struct Stats {
+    pub median: Duration,
+    pub min: Duration,
+    pub stddev: Duration,
+}
+
+impl Stats {
+    pub fn from_samples(samples: &[Duration]) -> Self {
+        let mut sorted = samples.to_vec();
+        sorted.sort();
+
+        let len = sorted.len();
+        let median = if len.is_multiple_of(2) {
+            (sorted[len / 2 - 1] + sorted[len / 2]) / 2
+        } else {
+            sorted[len / 2]
+        };
+
+        let nanos: Vec<f64> = sorted
+            .iter()
+            .map(|d| d.as_nanos() as f64)
+            .collect();
+        let mean = nanos.iter().sum::<f64>() / len as f64;
+        let variance = nanos
+            .iter()
+            .map(|n| (n - mean).powi(2))
+            .sum::<f64>()
+            / len as f64;
+
+        Stats {
+            median,
+            min: sorted[0],
+            stddev: Duration::from_nanos(variance.sqrt() as u64),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct BenchReport {
+    pub name: String,
+    pub iterations: usize,
+    pub parse: Stats,
+    pub part1: Stats,
+    pub part2: Stats,
+}
+
+// times parse, part 1 and part 2 separately, reading the input is excluded
+pub fn bench<S: Solution>(
+    name: &str,
+    input: &str,
+    warmup: usize,
+    iterations: usize,
+) -> BenchReport {
+    let iterations = iterations.max(1);
+
+    let mut parse = Vec::with_capacity(iterations);
+    let mut part1 = Vec::with_capacity(iterations);
+    let mut part2 = Vec::with_capacity(iterations);
+
+    for i in 0..warmup + iterations {
+        let (parsed, parse_time) = measure(|| S::parse(black_box(input)));
+        let (_, part1_time) = measure(|| black_box(S::part1(&parsed)));
+        let (_, part2_time) = measure(|| black_box(S::part2(&parsed)));
+
+        if i >= warmup {
+            parse.push(parse_time);
+            part1.push(part1_time);
+            part2.push(part2_time);
+        }
+    }
+
+    BenchReport {
+        name: name.to_string(),
+        iterations,
+        parse: Stats::from_samples(&parse),
+        part1: Stats::from_samples(&part1),
+        part2: Stats::from_samples(&part2),
+    }
+}
+
+impl BenchReport {
+    fn stages(&self) -> [(&str, &Stats); 3] {
+        [
+            ("parse", &self.parse),
+            ("part1", &self.part1),
+            ("part2", &self.part2),
+        ]
+    }
+
+    pub fn to_csv(&self, timestamp: u64) -> String {
+        self.stages()
+            .iter()
+            .map(|(stage, s)| {
+                format!(
+                    "{},{},{},{},{},{},{}\n",
+                    timestamp,
+                    self.name,
+                    stage,
+                    self.iterations,
+                    s.median.as_nanos(),
+                    s.min.as_nanos(),
+                    s.stddev.as_nanos()
+                )
+            })
+            .collect()
+    }
+
+    pub fn to_json(&self, timestamp: u64) -> String {
+        let stages: Vec<_> = self
+            .stages()
+            .iter()
+            .map(|(stage, s)| {
+                format!(
+                    "\"{}\":{{\"median_ns\":{},\"min_ns\":{},\"stddev_ns\":{}}}",
+                    stage,
+                    s.median.as_nanos(),
+                    s.min.as_nanos(),
+                    s.stddev.as_nanos()
+                )
+            })
+            .collect();
+
+        format!(
+            "{{\"timestamp\":{},\"name\":\"{}\",\"iterations\":{},{}}}\n",
+            timestamp,
+            self.name,
+            self.iterations,
+            stages.join(",")
+        )
+    }
+
+    // appends the report as JSON lines for `.json`/`.jsonl` files, as CSV
+    // otherwise
+    pub fn append_history(&self, path: &Path) -> io::Result<()> {
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_secs())
+            .unwrap_or(0);
+
+        let is_json = path
+            .extension()
+            .is_some_and(|e| e == "json" || e == "jsonl");
+
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+
+        let is_new = !path.exists();
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(path)?;
+
+        if is_json {
+            file.write_all(self.to_json(timestamp).as_bytes())
+        } else {
+            if is_new {
+                writeln!(file, "{}", CSV_HEADER)?;
+            }
+
+            file.write_all(self.to_csv(timestamp).as_bytes())
+        }
+    }
+}
+
+impl fmt::Display for BenchReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "{} ({} iterations)",
+            self.name, self.iterations
+        )?;
+        writeln!(
+            f,
+            "{:<8}{:>14}{:>14}{:>14}",
+            "stage", "median", "min", "stddev"
+        )?;
+
+        for (stage, s) in self.stages() {
+            writeln!(
+                f,
+                "{:<8}{:>14}{:>14}{:>14}",
+                stage,
+                format!("{:?}", s.median),
+                format!("{:?}", s.min),
+                format!("{:?}", s.stddev)
+            )?;
+        }
+
+        Ok(())
+    }
+}
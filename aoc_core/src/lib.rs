@@ -1,9 +1,12 @@
+pub mod bench;
 pub mod grid;
+pub mod parse;
+pub mod solution;
 pub mod spatial;
 pub mod tree;
 
 use std::fs;
-use std::time::Instant;
+use std::time::{Duration, Instant};
 
 pub fn start_measure() -> Instant {
     Instant::now()
@@ -13,6 +16,13 @@ pub fn end_measure(measure: Instant) {
     println!("\nFinished in {:?}", measure.elapsed());
 }
 
+pub fn measure<T>(f: impl FnOnce() -> T) -> (T, Duration) {
+    let mes = start_measure();
+    let res = f();
+
+    (res, mes.elapsed())
+}
+
 pub fn read(path: &str) -> String {
     fs::read_to_string(path).expect(&format!("could not open file {}", path))
 }
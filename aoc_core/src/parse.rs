@@ -0,0 +1,160 @@
+use std::fmt::Debug;
+use std::str::FromStr;
+
+// extracts all integers of a line, ignoring any text around them. A `-` is
+// only treated as a sign if it is not preceded by a digit (e.g. `v=3,-3`)
+pub fn ints<T>(line: &str) -> Vec<T>
+where
+    T: FromStr,
+    T::Err: Debug,
+{
+    let bytes = line.as_bytes();
+    let mut nums = Vec::new();
+    let mut i = 0;
+
+    while i < bytes.len() {
+        let is_sign = bytes[i] == b'-'
+            && bytes.get(i + 1).is_some_and(u8::is_ascii_digit)
+            && (i == 0 || !bytes[i - 1].is_ascii_digit());
+
+        if !is_sign && !bytes[i].is_ascii_digit() {
+            i += 1;
+            continue;
+        }
+
+        let start = i;
+        i += 1;
+
+        while i < bytes.len() && bytes[i].is_ascii_digit() {
+            i += 1;
+        }
+
+        nums.push(parse(&line[start..i]));
+    }
+
+    nums
+}
+
+// splits the input on blank lines, tolerating `\r\n` and trailing newlines
+pub fn sections(input: &str) -> Vec<&str> {
+    let mut sections = Vec::new();
+    let mut start = None;
+    let mut end = 0;
+    let mut offset = 0;
+
+    for line in input.split_inclusive('\n') {
+        let content = line.trim_end_matches(['\n', '\r']);
+
+        if content.trim().is_empty() {
+            if let Some(s) = start.take() {
+                sections.push(&input[s..end]);
+            }
+        } else {
+            start.get_or_insert(offset);
+            end = offset + content.len();
+        }
+
+        offset += line.len();
+    }
+
+    if let Some(s) = start {
+        sections.push(&input[s..end]);
+    }
+
+    sections
+}
+
+pub fn lines_of<T>(input: &str) -> Vec<T>
+where
+    T: FromStr,
+    T::Err: Debug,
+{
+    input
+        .lines()
+        .map(str::trim)
+        .filter(|l| !l.is_empty())
+        .map(parse)
+        .collect()
+}
+
+// parses whitespace separated columns, e.g. `3   4` lines into `[[3], [4]]`
+pub fn columns<T>(input: &str) -> Vec<Vec<T>>
+where
+    T: FromStr,
+    T::Err: Debug,
+{
+    let mut columns: Vec<Vec<T>> = Vec::new();
+
+    for line in input.lines().filter(|l| !l.trim().is_empty()) {
+        for (i, value) in line.split_whitespace().enumerate() {
+            if columns.len() <= i {
+                columns.push(Vec::new());
+            }
+
+            columns[i].push(parse(value));
+        }
+    }
+
+    columns
+}
+
+// splits `Register A: 729` into `("Register A", "729")`
+pub fn key_value(line: &str) -> Option<(&str, &str)> {
+    line.split_once(':')
+        .map(|(k, v)| (k.trim(), v.trim()))
+}
+
+pub fn key_values(input: &str) -> Vec<(&str, &str)> {
+    input.lines().filter_map(key_value).collect()
+}
+
+fn parse<T>(s: &str) -> T
+where
+    T: FromStr,
+    T::Err: Debug,
+{
+    s.parse()
+        .unwrap_or_else(|e| panic!("could not parse {:?}: {:?}", s, e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ints_signs() {
+        assert_eq!(ints::<i32>("p=0,4 v=3,-3"), vec![0, 4, 3, -3]);
+        assert_eq!(ints::<i32>("-7 -x 12-3"), vec![-7, 12, 3]);
+        assert_eq!(ints::<u64>("Button A: X+94, Y+34"), vec![94, 34]);
+        assert!(ints::<i32>("no numbers - here").is_empty());
+    }
+
+    #[test]
+    fn sections_newlines() {
+        let unix = "a\nb\n\nc\n\n\nd\n";
+        let windows = "a\r\nb\r\n\r\nc\r\n\r\n\r\nd\r\n";
+
+        assert_eq!(sections(unix), vec!["a\nb", "c", "d"]);
+        assert_eq!(sections(windows), vec!["a\r\nb", "c", "d"]);
+        assert_eq!(sections("\n\na\n  \nb"), vec!["a", "b"]);
+        assert!(sections("\n\n").is_empty());
+    }
+
+    #[test]
+    fn columns_uneven_rows() {
+        let input = "3   4\n1 2 9\n\n5\n";
+
+        assert_eq!(
+            columns::<u32>(input),
+            vec![vec![3, 1, 5], vec![4, 2], vec![9]]
+        );
+    }
+
+    #[test]
+    fn key_values_trimmed() {
+        assert_eq!(
+            key_values("Register A: 729\nno colon\nx00 : 1"),
+            vec![("Register A", "729"), ("x00", "1")]
+        );
+    }
+}
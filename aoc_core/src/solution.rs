@@ -0,0 +1,76 @@
+use std::env;
+use std::path::Path;
+
+use crate::bench::bench;
+use crate::{end_measure, read, start_measure};
+
+// same default as `aoc bench`
+const DEFAULT_WARMUP: usize = 5;
+
+// `aoc bench` starts a day with this variable set first, `run` answers by
+// printing it instead of solving, so days with their own arguments are
+// never handed the bench arguments
+pub const BENCH_PROBE: &str = "AOC_BENCH_PROBE";
+
+pub trait Solution {
+    type Input;
+
+    fn parse(input: &str) -> Self::Input;
+
+    fn part1(input: &Self::Input) -> String;
+
+    fn part2(input: &Self::Input) -> String;
+}
+
+// runs both parts, or benchmarks them when started with
+// `--bench <iterations> [--warmup <n>] [--history <file>]` (see `aoc bench`)
+pub fn run<S: Solution>(path: &str) {
+    if env::var_os(BENCH_PROBE).is_some() {
+        println!("{}", BENCH_PROBE);
+        return;
+    }
+
+    let args: Vec<String> = env::args().collect();
+
+    if let Some(iterations) = arg_value(&args, "--bench") {
+        let warmup = arg_value(&args, "--warmup").map_or(DEFAULT_WARMUP, |w| {
+            w.parse().expect("invalid --warmup")
+        });
+        let name = Path::new(&args[0])
+            .file_stem()
+            .map(|s| s.to_string_lossy().to_string())
+            .unwrap_or_default();
+
+        let report = bench::<S>(
+            &name,
+            &read(path),
+            warmup,
+            iterations.parse().expect("invalid --bench"),
+        );
+
+        print!("{}", report);
+
+        if let Some(history) = arg_value(&args, "--history") {
+            report
+                .append_history(Path::new(history))
+                .expect("could not write bench history");
+        }
+
+        return;
+    }
+
+    let mes = start_measure();
+    let input = S::parse(&read(path));
+
+    println!("part 1: {}", S::part1(&input));
+    println!("part 2: {}", S::part2(&input));
+
+    end_measure(mes);
+}
+
+fn arg_value<'a>(args: &'a [String], key: &str) -> Option<&'a str> {
+    args.iter()
+        .position(|a| a == key)
+        .and_then(|i| args.get(i + 1))
+        .map(String::as_str)
+}
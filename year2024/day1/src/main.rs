@@ -0,0 +1,24 @@
+use std::collections::HashMap;
+use aoc_core::parse::columns;
+use aoc_core::read;
+
+fn main() {
+    let lists: Vec<Vec<u32>> = columns(&read("in/input"));
+    let (left, right) = (&lists[0], &lists[1]);
+
+    let mut count_map = HashMap::new();
+    
+    for v in right {
+        let count = count_map.entry(v).or_insert(0);
+        *count += 1;
+    }
+
+    let mut sum: u32 = 0;
+
+    for v in left {
+        let count = count_map.get(v).unwrap_or(&0);
+        sum += count * v;
+    }
+
+    println!("{}", sum);
+}
\ No newline at end of file
@@ -0,0 +1,89 @@
+use aoc_core::grid::Grid;
+use aoc_core::solution::{run, Solution};
+use aoc_core::spatial::{Direction, Point, PointData};
+use std::collections::HashSet;
+
+const WALK_DIRECTIONS: [Direction; 4] = [
+    Direction::Up,
+    Direction::Right,
+    Direction::Down,
+    Direction::Left,
+];
+
+struct MapGrid {
+    grid: Grid<u8>,
+}
+
+impl<'a> MapGrid {
+    pub fn from_str(input: &str) -> MapGrid {
+        MapGrid {
+            grid: Grid::<u8>::from_string(input),
+        }
+    }
+
+    // every reached trailend once per distinct trail
+    fn walk_trailheads(&'a self) -> Vec<Vec<Point>> {
+        let mut trailends = vec![];
+
+        for pos in self.grid.iter() {
+            if pos.value == &0 {
+                let mut inner = vec![];
+                self.walk(pos, &mut inner);
+
+                trailends.push(inner);
+            }
+        }
+
+        trailends
+    }
+
+    fn walk(&'a self, origin: PointData<'a, u8>, trailends: &mut Vec<Point>) {
+        if origin.value == &9 {
+            trailends.push(origin.point);
+            return;
+        }
+
+        for dir in WALK_DIRECTIONS {
+            let neighbour = self.grid.move_to(&origin.point, &dir);
+
+            match neighbour {
+                None => {}
+                Some(n) => {
+                    if origin.value + 1 == *n.value {
+                        self.walk(n, trailends);
+                    }
+                }
+            }
+        }
+    }
+}
+
+struct Day;
+
+impl Solution for Day {
+    type Input = MapGrid;
+
+    fn parse(input: &str) -> Self::Input {
+        MapGrid::from_str(input)
+    }
+
+    fn part1(map: &Self::Input) -> String {
+        map.walk_trailheads()
+            .iter()
+            .map(|t| t.iter().collect::<HashSet<_>>().len())
+            .sum::<usize>()
+            .to_string()
+    }
+
+    fn part2(map: &Self::Input) -> String {
+        map.walk_trailheads()
+            .iter()
+            .map(|t| t.len())
+            .sum::<usize>()
+            .to_string()
+    }
+}
+
+fn main() {
+    run::<Day>("in/input");
+}
@@ -0,0 +1,108 @@
+mod rules;
+mod stones;
+
+use aoc_core::{end_measure, read, start_measure};
+use rules::{Rule, Rules};
+use std::collections::HashMap;
+use std::env;
+use stones::Stones;
+
+fn main() {
+    let mes = start_measure();
+
+    let input: Vec<u64> = read("in/input")
+        .split_whitespace()
+        .map(|x| x.parse().unwrap())
+        .collect();
+
+    // e.g. `cargo run --release -- 1000 base=8 parts=3`, a blink count
+    // prints the counts after every blink, the options change the rules
+    let (options, blinks): (Vec<_>, Vec<_>) =
+        env::args().skip(1).partition(|a| a.contains('='));
+
+    let mut rules = Rules::default();
+
+    for option in &options {
+        if let Err(e) = rules.set(option) {
+            println!("{}", e);
+            return;
+        }
+    }
+
+    if let Some(blinks) = blinks.first() {
+        let blinks = blinks.parse().expect("blinks is not a number");
+
+        evolve(&input, blinks, &rules);
+        end_measure(mes);
+        return;
+    }
+
+    const CYCLES: u8 = 75;
+
+    let mut lookup = HashMap::new();
+
+    let res: Result<usize, String> = input
+        .iter()
+        .map(|num| blink(*num, CYCLES, &mut lookup, &rules))
+        .sum();
+
+    match res {
+        Ok(res) => println!("{}", res),
+        Err(e) => println!("{}", e),
+    }
+
+    end_measure(mes);
+}
+
+fn evolve(input: &[u64], blinks: usize, rule: &dyn Rule) {
+    let mut stones = Stones::from_nums(input);
+
+    for i in 1..=blinks {
+        stones = match stones.blink(rule) {
+            Ok(stones) => stones,
+            Err(e) => {
+                println!("{:>5}: {}", i, e);
+                return;
+            }
+        };
+
+        let total = match stones.total() {
+            Some(total) => total.to_string(),
+            None => "more than u128".to_string(),
+        };
+
+        println!(
+            "{:>5}: {:>5} distinct, {} total",
+            i,
+            stones.distinct(),
+            total
+        );
+    }
+}
+
+fn blink(
+    num: u64,
+    cycles: u8,
+    lookup: &mut HashMap<(u64, u8), usize>,
+    rule: &dyn Rule,
+) -> Result<usize, String> {
+    if cycles == 0 {
+        return Ok(1);
+    }
+
+    let key = (num, cycles);
+
+    if lookup.contains_key(&key) {
+        return Ok(lookup[&key]);
+    }
+
+    let mut res = 0;
+
+    for next in rule.change(num)? {
+        res += blink(next, cycles - 1, lookup, rule)?;
+    }
+
+    lookup.insert(key, res);
+
+    Ok(res)
+}
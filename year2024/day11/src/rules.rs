@@ -0,0 +1,104 @@
+// what a stone turns into with one blink, or why it can't be represented
+pub trait Rule {
+    fn change(&self, num: u64) -> Result<Vec<u64>, String>;
+}
+
+// any closure works as a rule, e.g. `|n: u64| vec![n / 2, n / 2 + 1]`
+impl<F: Fn(u64) -> Vec<u64>> Rule for F {
+    fn change(&self, num: u64) -> Result<Vec<u64>, String> {
+        Ok(self(num))
+    }
+}
+
+// the puzzle rules with configurable numbers: 0 becomes `zero`, a number
+// with a digit count (in `base`) divisible by `parts` is split into that
+// many numbers and anything else is multiplied by `multiplier`
+#[derive(Debug, Clone, Copy)]
+pub struct Rules {
+    pub zero: u64,
+    pub base: u64,
+    pub parts: u32,
+    pub multiplier: u64,
+}
+
+impl Default for Rules {
+    fn default() -> Self {
+        Rules {
+            zero: 1,
+            base: 10,
+            parts: 2,
+            multiplier: 2024,
+        }
+    }
+}
+
+impl Rules {
+    // applies a `key=value` option, e.g. `base=8`
+    pub fn set(&mut self, option: &str) -> Result<(), String> {
+        let (key, value) = option
+            .split_once('=')
+            .ok_or(format!("{} is not a key=value option", option))?;
+
+        let value: u64 = value
+            .parse()
+            .map_err(|_| format!("{} is not a number", value))?;
+
+        match key {
+            "zero" => self.zero = value,
+            "base" if value >= 2 => self.base = value,
+            "parts" if value >= 1 => self.parts = value as u32,
+            "multiplier" => self.multiplier = value,
+            "base" | "parts" => {
+                return Err(format!("{} of {} is too small", key, value))
+            }
+            _ => return Err(format!("unknown rule option {}", key)),
+        }
+
+        Ok(())
+    }
+
+    fn digit_count(&self, mut num: u64) -> u32 {
+        let mut count = 1;
+
+        while num >= self.base {
+            num /= self.base;
+            count += 1;
+        }
+
+        count
+    }
+
+    // most significant part first
+    fn split(&self, mut num: u64, digit_count: u32) -> Vec<u64> {
+        let factor = self.base.pow(digit_count / self.parts);
+        let mut parts = vec![0; self.parts as usize];
+
+        for part in parts.iter_mut().rev() {
+            *part = num % factor;
+            num /= factor;
+        }
+
+        parts
+    }
+}
+
+impl Rule for Rules {
+    fn change(&self, num: u64) -> Result<Vec<u64>, String> {
+        if num == 0 {
+            return Ok(vec![self.zero]);
+        }
+
+        let digit_count = self.digit_count(num);
+
+        if self.parts > 1 && digit_count.is_multiple_of(self.parts) {
+            return Ok(self.split(num, digit_count));
+        }
+
+        let product = num.checked_mul(self.multiplier).ok_or(format!(
+            "stone {} times {} grows past u64",
+            num, self.multiplier
+        ))?;
+
+        Ok(vec![product])
+    }
+}
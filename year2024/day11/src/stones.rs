@@ -0,0 +1,62 @@
+use std::collections::HashMap;
+
+use crate::rules::Rule;
+
+// stones by their number, the order of the stones never matters for the
+// count. The numbers settle into a few thousand distinct values while the
+// total grows exponentially, so counts are u128 and checked for overflow.
+// Once a count overflowed the total is unknown but blinking continues
+#[derive(Debug, Clone)]
+pub struct Stones {
+    counts: HashMap<u64, u128>,
+    overflowed: bool,
+}
+
+impl Stones {
+    pub fn from_nums(nums: &[u64]) -> Self {
+        let mut counts = HashMap::new();
+
+        for num in nums {
+            *counts.entry(*num).or_default() += 1;
+        }
+
+        Stones {
+            counts,
+            overflowed: false,
+        }
+    }
+
+    pub fn blink(&self, rule: &dyn Rule) -> Result<Stones, String> {
+        let mut overflowed = self.overflowed;
+        let mut counts: HashMap<u64, u128> =
+            HashMap::with_capacity(self.counts.len());
+
+        for (num, count) in &self.counts {
+            for num in rule.change(*num)? {
+                let entry = counts.entry(num).or_default();
+
+                *entry = entry.checked_add(*count).unwrap_or_else(|| {
+                    overflowed = true;
+                    u128::MAX
+                });
+            }
+        }
+
+        Ok(Stones { counts, overflowed })
+    }
+
+    pub fn distinct(&self) -> usize {
+        self.counts.len()
+    }
+
+    // `None` once the count overflowed u128
+    pub fn total(&self) -> Option<u128> {
+        if self.overflowed {
+            return None;
+        }
+
+        self.counts
+            .values()
+            .try_fold(0u128, |sum, count| sum.checked_add(*count))
+    }
+}
@@ -1,5 +1,5 @@
+use aoc_core::parse::{ints, sections};
 use aoc_core::{end_measure, read, start_measure};
-use regex::Regex;
 
 #[derive(Debug)]
 struct ClawMachine {
@@ -13,23 +13,15 @@ struct ClawMachine {
 
 impl ClawMachine {
     fn from_string(s: &str, price_offset: f64) -> Self {
-        let captures: Vec<Vec<_>> = s
-            .lines()
-            .map(|l| {
-                Regex::new(r"(=|\+)([\d]+)")
-                    .unwrap()
-                    .captures_iter(l)
-                    .collect()
-            })
-            .collect();
+        let nums: Vec<f64> = ints(s);
 
         ClawMachine {
-            ax: captures[0][0][2].parse::<f64>().unwrap(),
-            ay: captures[0][1][2].parse::<f64>().unwrap(),
-            bx: captures[1][0][2].parse::<f64>().unwrap(),
-            by: captures[1][1][2].parse::<f64>().unwrap(),
-            rx: price_offset + captures[2][0][2].parse::<f64>().unwrap(),
-            ry: price_offset + captures[2][1][2].parse::<f64>().unwrap(),
+            ax: nums[0],
+            ay: nums[1],
+            bx: nums[2],
+            by: nums[3],
+            rx: price_offset + nums[4],
+            ry: price_offset + nums[5],
         }
     }
 
@@ -50,8 +42,8 @@ fn main() {
 
     let input = read("in/input");
 
-    let sum: f64 = input
-        .split("\n\n")
+    let sum: f64 = sections(&input)
+        .into_iter()
         .map(|l| ClawMachine::from_string(l, 10000000000000.0).get_cheapest_tokens())
         .sum();
 
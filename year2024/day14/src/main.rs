@@ -1,3 +1,4 @@
+use aoc_core::parse::ints;
 use aoc_core::spatial::Point;
 use aoc_core::{end_measure, read, start_measure};
 use std::collections::HashSet;
@@ -22,18 +23,11 @@ impl BathroomLobby {
     fn from_string(width: u32, height: u32, input: &str) -> Self {
         let robots: Vec<_> = input
             .lines()
-            .map(|l| {
-                l.replace("p=", "")
-                    .split(" v=")
-                    .map(|s| {
-                        s.split(',')
-                            .map(|p| p.parse::<i32>().unwrap())
-                            .collect()
-                    })
-                    .map(|p: Vec<_>| Point { x: p[0], y: p[1] })
-                    .collect()
+            .map(ints::<i32>)
+            .map(|n| Robot {
+                pos: Point { x: n[0], y: n[1] },
+                v: Point { x: n[2], y: n[3] },
             })
-            .map(|p: Vec<_>| Robot { pos: p[0], v: p[1] })
             .collect();
 
         BathroomLobby {
@@ -0,0 +1,294 @@
+mod replay;
+
+use std::io::{self, BufRead};
+use std::time::Duration;
+use std::{env, thread};
+
+use aoc_core::grid::Grid;
+use aoc_core::spatial::{Direction, OwnedPointData, Point, PointData};
+use aoc_core::tree::{TreeNode, TreeRoot};
+use aoc_core::{end_measure, read, start_measure};
+use replay::{Push, Replay};
+
+fn move_to_direction(c: char) -> Option<Direction> {
+    match c {
+        '^' => Some(Direction::Up),
+        '>' => Some(Direction::Right),
+        'v' => Some(Direction::Down),
+        '<' => Some(Direction::Left),
+        _ => None,
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Scale {
+    // `O` boxes as in the scanned map
+    Narrow,
+    // everything twice as wide, boxes are `[]`
+    Wide,
+}
+
+struct RobotGrid {
+    map: Grid<char>,
+    robot: Point<i32>,
+}
+
+impl RobotGrid {
+    pub fn from_str(map: &str, scale: Scale) -> Self {
+        let map = match scale {
+            Scale::Narrow => map.to_string(),
+            Scale::Wide => RobotGrid::scale_up(map),
+        };
+
+        let map = Grid::<char>::from_string(&map);
+        let robot = map.find('@').unwrap();
+
+        RobotGrid { map, robot }
+    }
+
+    pub fn scale_up(line: &str) -> String {
+        line.chars()
+            .flat_map(|c| match c {
+                '#' => "##".chars(),
+                'O' => "[]".chars(),
+                '.' => "..".chars(),
+                '@' => "@.".chars(),
+                '\n' => "\n".chars(),
+                _ => "".chars(),
+            })
+            .collect()
+    }
+
+    fn walk_all(&mut self, moves: impl Iterator<Item = Direction>) {
+        for direction in moves {
+            self.walk(direction);
+        }
+    }
+
+    // the push that was made, `None` if a wall is in the way
+    fn walk(&mut self, direction: Direction) -> Option<Push> {
+        let initial_robot = self.robot;
+
+        let mut robot_node = TreeNode::new(OwnedPointData {
+            point: initial_robot,
+            value: '@',
+        });
+
+        // wall is hit somewhere
+        robot_node.children =
+            self.get_moveable_boxes(&initial_robot, &direction)?;
+
+        let tree = TreeRoot::new(Some(robot_node));
+        let push = Push::from_tree(&tree, direction);
+
+        self.apply(&push);
+
+        Some(push)
+    }
+
+    fn apply(&mut self, push: &Push) {
+        push.apply(&mut self.map);
+        self.robot = self.robot.neighbour(&push.direction);
+    }
+
+    fn undo(&mut self, push: &Push) {
+        push.undo(&mut self.map);
+        self.robot = self.robot.sub(&push.direction.as_point());
+    }
+
+    fn get_moveable_boxes(
+        &self,
+        from: &Point,
+        direction: &Direction,
+    ) -> Option<Vec<TreeNode<OwnedPointData<char>>>> {
+        let pointer = self.map.move_to(&from, direction).unwrap();
+        let mut box_parts: Vec<_> = vec![];
+        let mut nodes: Vec<_> = vec![];
+
+        if *pointer.value == '#' {
+            return None;
+        }
+
+        if *pointer.value == 'O' {
+            box_parts.push(pointer);
+        }
+
+        if *pointer.value == '[' || *pointer.value == ']' {
+            if *direction == Direction::Up || *direction == Direction::Down {
+                box_parts = RobotGrid::get_box_parts(pointer);
+            } else {
+                box_parts.push(pointer);
+            }
+        }
+
+        for box_part in box_parts {
+            let owned_data = OwnedPointData::from_point_data(box_part);
+            let mut node = TreeNode::new(owned_data);
+
+            let moveable_boxes =
+                self.get_moveable_boxes(&box_part.point, direction);
+
+            match moveable_boxes {
+                Some(children) => {
+                    node.children = children;
+                    nodes.push(node);
+                }
+                None => return None,
+            }
+        }
+
+        Some(nodes)
+    }
+
+    fn get_box_parts(pointer: PointData<char>) -> Vec<PointData<char>> {
+        if *pointer.value == '[' {
+            let box_end = PointData {
+                value: &']',
+                point: Point {
+                    x: pointer.point.x + 1,
+                    y: pointer.point.y,
+                },
+            };
+
+            return vec![pointer, box_end];
+        }
+
+        if *pointer.value == ']' {
+            let box_start = PointData {
+                value: &'[',
+                point: Point {
+                    x: pointer.point.x - 1,
+                    y: pointer.point.y,
+                },
+            };
+
+            return vec![box_start, pointer];
+        }
+
+        vec![]
+    }
+
+    fn get_gps_coords(&self) -> i32 {
+        self.map
+            .iter()
+            .filter(|p| *p.value == 'O' || *p.value == '[')
+            .map(|b| b.point.x + b.point.y * 100)
+            .sum()
+    }
+}
+
+fn parse_moves(moves: &str) -> impl Iterator<Item = Direction> + '_ {
+    moves.chars().filter_map(move_to_direction)
+}
+
+// plays all moves, stops at the first broken invariant and rewinds to the
+// start afterwards to check the recorded pushes
+fn animate(replay: &mut Replay, delay: Duration) {
+    let initial = replay.grid.map.to_string();
+
+    println!("{}", replay.frame());
+
+    while replay.step_forward() {
+        thread::sleep(delay);
+        println!("{}", replay.frame());
+
+        if let Err(e) = replay.check() {
+            println!(
+                "invariant broken after move {}: {}",
+                replay.position(),
+                e
+            );
+            return;
+        }
+    }
+
+    println!("gps: {}", replay.grid.get_gps_coords());
+
+    while replay.step_back() {}
+
+    if replay.grid.map.to_string() != initial {
+        println!("undoing all moves does not restore the initial map");
+    }
+}
+
+fn step(replay: &mut Replay) {
+    println!("{}", replay.frame());
+
+    for line in io::stdin().lock().lines() {
+        let moved = match line.unwrap().trim() {
+            "q" => return,
+            "b" => replay.step_back(),
+            _ => replay.step_forward(),
+        };
+
+        println!("{}", replay.frame());
+
+        if !moved {
+            println!("no more moves in that direction");
+        }
+
+        if let Err(e) = replay.check() {
+            println!("invariant broken: {}", e);
+        }
+    }
+}
+
+fn main() {
+    let mes = start_measure();
+    let input = read("in/input");
+
+    let split: Vec<_> = input.split("\n\n").collect();
+    let map = split.first().unwrap();
+    let moves = split.get(1).unwrap().trim();
+
+    let args: Vec<String> = env::args().collect();
+    let arg = |i: usize| args.get(i).map(String::as_str);
+
+    let scale_arg = |i: usize| match arg(i) {
+        Some("narrow") => Some(Scale::Narrow),
+        Some("wide") => Some(Scale::Wide),
+        _ => None,
+    };
+
+    // `cargo run -- replay [narrow|wide] [delay in ms]` animates every move,
+    // `step [narrow|wide]` steps forward and back on enter, `b` and `q`
+    if let Some(mode @ ("replay" | "step")) = arg(1) {
+        let grid =
+            RobotGrid::from_str(map, scale_arg(2).unwrap_or(Scale::Wide));
+        let mut replay = Replay::new(grid, parse_moves(moves).collect());
+
+        if mode == "replay" {
+            let delay = arg(3)
+                .map_or(50, |d| d.parse().expect("delay is not a number"));
+            animate(&mut replay, Duration::from_millis(delay));
+        } else {
+            step(&mut replay);
+        }
+
+        end_measure(mes);
+        return;
+    }
+
+    // `cargo run -- narrow` or `wide` prints the map before and after
+    let scale = scale_arg(1);
+
+    if let Some(scale) = scale {
+        let mut grid = RobotGrid::from_str(map, scale);
+
+        println!("{}", grid.map);
+        grid.walk_all(parse_moves(moves));
+        println!("{}", grid.map);
+
+        println!("{}", grid.get_gps_coords());
+    } else {
+        for (name, scale) in [("narrow", Scale::Narrow), ("wide", Scale::Wide)]
+        {
+            let mut grid = RobotGrid::from_str(map, scale);
+            grid.walk_all(parse_moves(moves));
+
+            println!("{}: {}", name, grid.get_gps_coords());
+        }
+    }
+
+    end_measure(mes);
+}
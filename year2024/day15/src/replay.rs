@@ -0,0 +1,227 @@
+use std::collections::HashSet;
+use std::fmt;
+
+use aoc_core::grid::Grid;
+use aoc_core::spatial::{Direction, OwnedPointData, Point};
+use aoc_core::tree::TreeRoot;
+
+use crate::RobotGrid;
+
+fn direction_char(direction: &Direction) -> char {
+    match direction {
+        Direction::Up => '^',
+        Direction::Right => '>',
+        Direction::Down => 'v',
+        _ => '<',
+    }
+}
+
+// every cell moved by one step of the robot (the robot included) with its
+// content before the move
+#[derive(Debug, Clone)]
+pub struct Push {
+    pub direction: Direction,
+    pub cells: Vec<(Point, char)>,
+}
+
+impl Push {
+    // a box half can be in the tree more than once in wide warehouses
+    pub fn from_tree(
+        tree: &TreeRoot<OwnedPointData<char>>,
+        direction: Direction,
+    ) -> Self {
+        let mut seen = HashSet::new();
+
+        let cells = tree
+            .iter_breadth()
+            .filter(|n| seen.insert(n.value.point))
+            .map(|n| (n.value.point, n.value.value))
+            .collect();
+
+        Push { direction, cells }
+    }
+
+    pub fn boxes(&self) -> usize {
+        self.cells
+            .iter()
+            .filter(|(_, c)| *c == 'O' || *c == '[')
+            .count()
+    }
+
+    // all cells are cleared before any is set, so their order doesn't matter
+    pub fn apply(&self, map: &mut Grid<char>) {
+        for (point, _) in &self.cells {
+            map.set(point, '.');
+        }
+
+        for (point, value) in &self.cells {
+            map.set(&point.neighbour(&self.direction), *value);
+        }
+    }
+
+    pub fn undo(&self, map: &mut Grid<char>) {
+        for (point, _) in &self.cells {
+            map.set(&point.neighbour(&self.direction), '.');
+        }
+
+        for (point, value) in &self.cells {
+            map.set(point, *value);
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InvariantError {
+    OpenBox(Point),
+    ClosedBox(Point),
+    RobotMissing(Point),
+    RobotCount(usize),
+    BoxCount { expected: usize, found: usize },
+}
+
+impl fmt::Display for InvariantError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InvariantError::OpenBox(p) => {
+                write!(f, "[ at {},{} has no ] to its right", p.x, p.y)
+            }
+            InvariantError::ClosedBox(p) => {
+                write!(f, "] at {},{} has no [ to its left", p.x, p.y)
+            }
+            InvariantError::RobotMissing(p) => {
+                write!(f, "robot is not on the map at {},{}", p.x, p.y)
+            }
+            InvariantError::RobotCount(count) => {
+                write!(f, "map contains {} robots", count)
+            }
+            InvariantError::BoxCount { expected, found } => {
+                write!(
+                    f,
+                    "map contains {} boxes instead of {}",
+                    found, expected
+                )
+            }
+        }
+    }
+}
+
+// steps through the moves one at a time, every move made is recorded so it
+// can be taken back again
+pub struct Replay {
+    pub grid: RobotGrid,
+    moves: Vec<Direction>,
+    // one entry per move made, `None` where the robot walked into a wall
+    history: Vec<Option<Push>>,
+    boxes: usize,
+}
+
+impl Replay {
+    pub fn new(grid: RobotGrid, moves: Vec<Direction>) -> Self {
+        let boxes = Replay::count_boxes(&grid.map);
+
+        Replay {
+            grid,
+            moves,
+            history: vec![],
+            boxes,
+        }
+    }
+
+    fn count_boxes(map: &Grid<char>) -> usize {
+        map.iter()
+            .filter(|p| *p.value == 'O' || *p.value == '[')
+            .count()
+    }
+
+    // number of moves made
+    pub fn position(&self) -> usize {
+        self.history.len()
+    }
+
+    pub fn step_forward(&mut self) -> bool {
+        let Some(direction) = self.moves.get(self.history.len()) else {
+            return false;
+        };
+
+        let push = self.grid.walk(*direction);
+        self.history.push(push);
+
+        true
+    }
+
+    pub fn step_back(&mut self) -> bool {
+        let Some(push) = self.history.pop() else {
+            return false;
+        };
+
+        if let Some(push) = push {
+            self.grid.undo(&push);
+        }
+
+        true
+    }
+
+    pub fn check(&self) -> Result<(), InvariantError> {
+        let map = &self.grid.map;
+        let mut robots = 0;
+
+        for p in map.iter() {
+            let right = map.get(&p.point.neighbour(&Direction::Right));
+            let left = map.get(&p.point.neighbour(&Direction::Left));
+
+            match p.value {
+                '[' if right != Some(&']') => {
+                    return Err(InvariantError::OpenBox(p.point))
+                }
+                ']' if left != Some(&'[') => {
+                    return Err(InvariantError::ClosedBox(p.point))
+                }
+                '@' => robots += 1,
+                _ => {}
+            }
+        }
+
+        if map.get(&self.grid.robot) != Some(&'@') {
+            return Err(InvariantError::RobotMissing(self.grid.robot));
+        }
+
+        if robots != 1 {
+            return Err(InvariantError::RobotCount(robots));
+        }
+
+        let found = Replay::count_boxes(map);
+
+        if found != self.boxes {
+            return Err(InvariantError::BoxCount {
+                expected: self.boxes,
+                found,
+            });
+        }
+
+        Ok(())
+    }
+
+    // the map after the last move, starting with a terminal clear
+    pub fn frame(&self) -> String {
+        let last = match self.history.last() {
+            Some(Some(push)) => format!(
+                "{} pushed {} boxes",
+                direction_char(&push.direction),
+                push.boxes()
+            ),
+            Some(None) => format!(
+                "{} blocked",
+                direction_char(&self.moves[self.history.len() - 1])
+            ),
+            None => "start".to_string(),
+        };
+
+        format!(
+            "\x1b[2J\x1b[H{}\nmove {}/{}: {}",
+            self.grid.map,
+            self.position(),
+            self.moves.len(),
+            last
+        )
+    }
+}
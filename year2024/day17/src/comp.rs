@@ -0,0 +1,271 @@
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+
+use aoc_core::parse::key_values;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CompError {
+    InvalidOpcode { ptr: usize, opcode: u8 },
+    MissingOperand { ptr: usize },
+    ReservedOperand { ptr: usize },
+    StepLimit { steps: usize },
+}
+
+impl fmt::Display for CompError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CompError::InvalidOpcode { ptr, opcode } => {
+                write!(f, "invalid opcode {} at {}", opcode, ptr)
+            }
+            CompError::MissingOperand { ptr } => {
+                write!(f, "missing operand at {}", ptr)
+            }
+            CompError::ReservedOperand { ptr } => {
+                write!(f, "reserved combo operand 7 at {}", ptr)
+            }
+            CompError::StepLimit { steps } => {
+                write!(f, "no halt after {} steps", steps)
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Halt {
+    Finished,
+    Breakpoint(usize),
+}
+
+// registers are the state after executing the instruction
+#[derive(Debug, Clone, Copy)]
+pub struct Step {
+    pub ptr: usize,
+    pub opcode: u8,
+    pub operand: u8,
+    pub a: u64,
+    pub b: u64,
+    pub c: u64,
+}
+
+impl fmt::Display for Step {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{:02}: {} {}  A={} B={} C={}",
+            self.ptr, self.opcode, self.operand, self.a, self.b, self.c
+        )
+    }
+}
+
+#[derive(Debug)]
+pub struct ThreeBitComp {
+    pub a: u64,
+    pub b: u64,
+    pub c: u64,
+    pub ptr: usize,
+    pub prog: Vec<u8>,
+    pub out: Vec<u8>,
+    breakpoints: HashSet<usize>,
+    trace: Option<Vec<Step>>,
+}
+
+impl ThreeBitComp {
+    pub fn from_str(input: &str) -> Self {
+        let values: HashMap<_, _> = key_values(input).into_iter().collect();
+
+        let prog = values["Program"]
+            .split(',')
+            .map(|s| s.parse().unwrap())
+            .collect();
+
+        let nums: Vec<u64> = ["Register A", "Register B", "Register C"]
+            .iter()
+            .map(|k| values[k].parse().unwrap())
+            .collect();
+
+        ThreeBitComp {
+            a: nums[0],
+            b: nums[1],
+            c: nums[2],
+            ptr: 0,
+            prog,
+            out: vec![],
+            breakpoints: HashSet::new(),
+            trace: None,
+        }
+    }
+
+    pub fn init(&mut self, val: u64) {
+        self.a = val;
+        self.b = 0;
+        self.c = 0;
+
+        self.ptr = 0;
+        self.out.clear();
+
+        if let Some(trace) = &mut self.trace {
+            trace.clear();
+        }
+    }
+
+    pub fn add_breakpoint(&mut self, ptr: usize) {
+        self.breakpoints.insert(ptr);
+    }
+
+    pub fn enable_trace(&mut self) {
+        self.trace = Some(vec![]);
+    }
+
+    pub fn trace(&self) -> &[Step] {
+        self.trace.as_deref().unwrap_or(&[])
+    }
+
+    // executes a single instruction, `None` if the program has halted
+    pub fn step(&mut self) -> Result<Option<Step>, CompError> {
+        let ptr = self.ptr;
+
+        let Some(&opcode) = self.prog.get(ptr) else {
+            return Ok(None);
+        };
+
+        let operand = *self
+            .prog
+            .get(ptr + 1)
+            .ok_or(CompError::MissingOperand { ptr })?;
+
+        self.ptr += 2;
+
+        match opcode {
+            0 => self.a = self.dv(self.comb(operand)?),
+            1 => self.b ^= operand as u64,
+            2 => self.b = self.comb(operand)? % 8,
+            3 => {
+                if self.a != 0 {
+                    self.ptr = operand as usize
+                }
+            }
+            4 => self.b ^= self.c,
+            5 => self.out.push((self.comb(operand)? % 8) as u8),
+            6 => self.b = self.dv(self.comb(operand)?),
+            7 => self.c = self.dv(self.comb(operand)?),
+            _ => return Err(CompError::InvalidOpcode { ptr, opcode }),
+        }
+
+        let step = Step {
+            ptr,
+            opcode,
+            operand,
+            a: self.a,
+            b: self.b,
+            c: self.c,
+        };
+
+        if let Some(trace) = &mut self.trace {
+            trace.push(step);
+        }
+
+        Ok(Some(step))
+    }
+
+    // runs until the program halts or a breakpoint is reached. A breakpoint
+    // stops before its instruction, so calling `run` again resumes from it
+    pub fn run(&mut self, max_steps: usize) -> Result<Halt, CompError> {
+        for i in 0..max_steps {
+            if i > 0 && self.breakpoints.contains(&self.ptr) {
+                return Ok(Halt::Breakpoint(self.ptr));
+            }
+
+            if self.step()?.is_none() {
+                return Ok(Halt::Finished);
+            }
+        }
+
+        if self.ptr >= self.prog.len() {
+            return Ok(Halt::Finished);
+        }
+
+        Err(CompError::StepLimit { steps: max_steps })
+    }
+
+    // runs the program with `val` in A, ignoring breakpoints
+    pub fn output_for(
+        &mut self,
+        val: u64,
+        max_steps: usize,
+    ) -> Result<Vec<u8>, CompError> {
+        self.init(val);
+
+        for _ in 0..max_steps {
+            if self.step()?.is_none() {
+                return Ok(self.out.clone());
+            }
+        }
+
+        Err(CompError::StepLimit { steps: max_steps })
+    }
+
+    fn comb(&self, operand: u8) -> Result<u64, CompError> {
+        match operand {
+            0..=3 => Ok(operand as u64),
+            4 => Ok(self.a),
+            5 => Ok(self.b),
+            6 => Ok(self.c),
+            _ => Err(CompError::ReservedOperand { ptr: self.ptr - 2 }),
+        }
+    }
+
+    fn dv(&self, shift: u64) -> u64 {
+        if shift >= 64 {
+            0
+        } else {
+            self.a >> shift
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn comp(a: u64, prog: &str) -> ThreeBitComp {
+        ThreeBitComp::from_str(&format!(
+            "Register A: {}\nRegister B: 0\nRegister C: 0\n\nProgram: {}",
+            a, prog
+        ))
+    }
+
+    #[test]
+    fn reserved_operand() {
+        assert_eq!(
+            comp(0, "5,7").run(10),
+            Err(CompError::ReservedOperand { ptr: 0 })
+        );
+    }
+
+    #[test]
+    fn step_limit() {
+        assert_eq!(
+            comp(1, "3,0").run(50),
+            Err(CompError::StepLimit { steps: 50 })
+        );
+    }
+
+    #[test]
+    fn breakpoint_resume() -> Result<(), CompError> {
+        let mut comp = comp(729, "0,1,5,4,3,0");
+        comp.add_breakpoint(2);
+
+        assert_eq!(comp.run(100), Ok(Halt::Breakpoint(2)));
+        assert!(comp.out.is_empty());
+
+        // resuming executes the `out` the breakpoint stopped at
+        assert_eq!(comp.run(100), Ok(Halt::Breakpoint(2)));
+        assert_eq!(comp.out, vec![4]);
+
+        while comp.run(100)? == Halt::Breakpoint(2) {}
+
+        assert_eq!(comp.out, vec![4, 6, 3, 5, 6, 3, 5, 2, 1, 0]);
+
+        Ok(())
+    }
+}
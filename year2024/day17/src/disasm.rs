@@ -0,0 +1,185 @@
+use std::collections::BTreeSet;
+use std::fmt;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Opcode {
+    Adv,
+    Bxl,
+    Bst,
+    Jnz,
+    Bxc,
+    Out,
+    Bdv,
+    Cdv,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Operand {
+    Literal(u8),
+    A,
+    B,
+    C,
+    Reserved,
+    Label(usize),
+    Ignored,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Instruction {
+    pub addr: usize,
+    pub opcode: Opcode,
+    pub operand: Operand,
+}
+
+impl Opcode {
+    pub fn from_u8(n: u8) -> Option<Opcode> {
+        match n {
+            0 => Some(Opcode::Adv),
+            1 => Some(Opcode::Bxl),
+            2 => Some(Opcode::Bst),
+            3 => Some(Opcode::Jnz),
+            4 => Some(Opcode::Bxc),
+            5 => Some(Opcode::Out),
+            6 => Some(Opcode::Bdv),
+            7 => Some(Opcode::Cdv),
+            _ => None,
+        }
+    }
+
+    pub fn mnemonic(&self) -> &str {
+        match self {
+            Opcode::Adv => "adv",
+            Opcode::Bxl => "bxl",
+            Opcode::Bst => "bst",
+            Opcode::Jnz => "jnz",
+            Opcode::Bxc => "bxc",
+            Opcode::Out => "out",
+            Opcode::Bdv => "bdv",
+            Opcode::Cdv => "cdv",
+        }
+    }
+
+    pub fn operand(&self, n: u8) -> Operand {
+        match self {
+            Opcode::Bxl => Operand::Literal(n),
+            Opcode::Jnz => Operand::Label(n as usize),
+            Opcode::Bxc => Operand::Ignored,
+            _ => Operand::combo(n),
+        }
+    }
+}
+
+impl Operand {
+    pub fn combo(n: u8) -> Operand {
+        match n {
+            0..=3 => Operand::Literal(n),
+            4 => Operand::A,
+            5 => Operand::B,
+            6 => Operand::C,
+            _ => Operand::Reserved,
+        }
+    }
+}
+
+impl fmt::Display for Operand {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Operand::Literal(n) => write!(f, "{}", n),
+            Operand::A => write!(f, "A"),
+            Operand::B => write!(f, "B"),
+            Operand::C => write!(f, "C"),
+            Operand::Reserved => write!(f, "?7"),
+            Operand::Label(addr) => write!(f, "L{}", addr),
+            Operand::Ignored => Ok(()),
+        }
+    }
+}
+
+impl Instruction {
+    // readable pseudo code of what the instruction does
+    pub fn describe(&self) -> String {
+        let op = self.operand;
+
+        match self.opcode {
+            Opcode::Adv => format!("A = A >> {}", op),
+            Opcode::Bxl => format!("B = B ^ {}", op),
+            Opcode::Bst => format!("B = {} % 8", op),
+            Opcode::Jnz => format!("if A != 0 goto {}", op),
+            Opcode::Bxc => "B = B ^ C".to_string(),
+            Opcode::Out => format!("out {} % 8", op),
+            Opcode::Bdv => format!("B = A >> {}", op),
+            Opcode::Cdv => format!("C = A >> {}", op),
+        }
+    }
+}
+
+pub fn decode(prog: &[u8]) -> Vec<Instruction> {
+    prog.chunks_exact(2)
+        .enumerate()
+        .filter_map(|(i, chunk)| {
+            let opcode = Opcode::from_u8(chunk[0])?;
+
+            Some(Instruction {
+                addr: i * 2,
+                opcode,
+                operand: opcode.operand(chunk[1]),
+            })
+        })
+        .collect()
+}
+
+pub fn disassemble(prog: &[u8]) -> String {
+    let instructions = decode(prog);
+    let labels: BTreeSet<usize> = instructions
+        .iter()
+        .filter_map(|i| match i.operand {
+            Operand::Label(addr) => Some(addr),
+            _ => None,
+        })
+        .collect();
+
+    let mut out = String::new();
+
+    for inst in &instructions {
+        if labels.contains(&inst.addr) {
+            out.push_str(&format!("L{}:\n", inst.addr));
+        }
+
+        let asm = format!("{} {}", inst.opcode.mnemonic(), inst.operand);
+        let mut line = format!(
+            "  {:02}  {:<8}; {}",
+            inst.addr,
+            asm,
+            inst.describe()
+        );
+
+        if inst.operand == Operand::Reserved {
+            line.push_str("  [reserved operand 7]");
+        }
+
+        out.push_str(line.trim_end());
+        out.push('\n');
+    }
+
+    for label in &labels {
+        if label % 2 == 1 || *label >= prog.len() {
+            out.push_str(&format!(
+                "; warning: L{} does not point to an instruction\n",
+                label
+            ));
+        }
+    }
+
+    if let Some(n) = prog.iter().find(|n| **n > 7) {
+        out.push_str(&format!(
+            "; warning: {} is not a three-bit value\n",
+            n
+        ));
+    }
+
+    if prog.len() % 2 == 1 {
+        out.push_str("; warning: last opcode has no operand\n");
+    }
+
+    out
+}
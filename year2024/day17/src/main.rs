@@ -0,0 +1,97 @@
+mod comp;
+mod disasm;
+mod quine;
+mod symbolic;
+
+use std::env;
+
+use aoc_core::{end_measure, read, start_measure};
+use comp::{Halt, ThreeBitComp};
+use disasm::disassemble;
+use quine::find_quine;
+use symbolic::analyse;
+
+const MAX_STEPS: usize = 1_000_000;
+
+fn main() {
+    let mes = start_measure();
+    let input = read("in/input");
+
+    let mut comp = ThreeBitComp::from_str(&input);
+
+    let args: Vec<String> = env::args().skip(1).collect();
+
+    match args.first().map(String::as_str) {
+        Some("disasm") => {
+            print!("{}", disassemble(&comp.prog));
+            return;
+        }
+        Some("analyse") => {
+            match analyse(&comp.prog) {
+                Ok(report) => print!("{}", report),
+                Err(e) => println!("cannot analyse program: {}", e),
+            }
+
+            return;
+        }
+        Some("trace") => {
+            for ptr in &args[1..] {
+                comp.add_breakpoint(ptr.parse().expect("invalid breakpoint"));
+            }
+
+            trace(&mut comp);
+            return;
+        }
+        _ => {}
+    }
+
+    match comp.run(MAX_STEPS) {
+        Ok(_) => println!("output: {}", join(&comp.out)),
+        Err(e) => println!("program failed: {}", e),
+    }
+
+    let prog = comp.prog.clone();
+
+    match find_quine(&prog, |a| comp.output_for(a, MAX_STEPS)) {
+        Ok(a) => println!("copied with init: {}", a),
+        Err(e) => println!("cannot search for a quine: {}", e),
+    }
+
+    end_measure(mes);
+}
+
+// prints every executed instruction, pausing at breakpoints
+fn trace(comp: &mut ThreeBitComp) {
+    comp.enable_trace();
+    let mut printed = 0;
+
+    loop {
+        let halt = comp.run(MAX_STEPS);
+
+        for step in &comp.trace()[printed..] {
+            println!("{}", step);
+        }
+
+        printed = comp.trace().len();
+
+        match halt {
+            Ok(Halt::Breakpoint(ptr)) => {
+                println!("-- break at {} (out: {})", ptr, join(&comp.out))
+            }
+            Ok(Halt::Finished) => break,
+            Err(e) => {
+                println!("-- {}", e);
+                break;
+            }
+        }
+    }
+
+    println!("output: {}", join(&comp.out));
+}
+
+fn join(out: &[u8]) -> String {
+    out.iter()
+        .map(|n| n.to_string())
+        .collect::<Vec<_>>()
+        .join(",")
+}
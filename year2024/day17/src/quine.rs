@@ -0,0 +1,118 @@
+use crate::comp::CompError;
+use crate::disasm::{decode, Opcode, Operand};
+
+// the search relies on the program being a single loop which outputs one
+// digit and shifts A by 3 bits per iteration, so each output digit only
+// depends on the next three bits of A (plus the bits above them)
+pub fn check_shape(prog: &[u8]) -> Result<(), String> {
+    let instructions = decode(prog);
+
+    if prog.len() % 2 == 1 || instructions.len() != prog.len() / 2 {
+        return Err("program contains invalid instructions".to_string());
+    }
+
+    if prog.len() > 21 {
+        return Err("program is too long for a 64 bit register A".to_string());
+    }
+
+    let count = |opcode: Opcode| {
+        instructions
+            .iter()
+            .filter(|i| i.opcode == opcode)
+            .count()
+    };
+
+    let last = instructions.last().ok_or("program is empty")?;
+
+    if last.opcode != Opcode::Jnz || last.operand != Operand::Label(0) {
+        return Err("program does not end with `jnz L0`".to_string());
+    }
+
+    if count(Opcode::Jnz) != 1 {
+        return Err("program contains more than one jump".to_string());
+    }
+
+    if count(Opcode::Out) != 1 {
+        return Err(format!(
+            "expected one `out` per iteration, found {}",
+            count(Opcode::Out)
+        ));
+    }
+
+    let shifts_a_by_3 = instructions
+        .iter()
+        .any(|i| i.opcode == Opcode::Adv && i.operand == Operand::Literal(3));
+
+    if count(Opcode::Adv) != 1 || !shifts_a_by_3 {
+        return Err(
+            "A is not shifted by exactly 3 bits per iteration".to_string()
+        );
+    }
+
+    Ok(())
+}
+
+// finds the minimal A for which the program outputs itself by building A
+// three bits at a time, starting with the last output digit
+pub fn find_quine(
+    prog: &[u8],
+    mut run: impl FnMut(u64) -> Result<Vec<u8>, CompError>,
+) -> Result<u64, String> {
+    check_shape(prog)?;
+
+    search(prog, prog.len(), 0, &mut run)
+        .map_err(|e| format!("program failed: {}", e))?
+        .ok_or("no value of A makes the program output itself".to_string())
+}
+
+fn search(
+    prog: &[u8],
+    remaining: usize,
+    prefix: u64,
+    run: &mut impl FnMut(u64) -> Result<Vec<u8>, CompError>,
+) -> Result<Option<u64>, CompError> {
+    if remaining == 0 {
+        return Ok(Some(prefix));
+    }
+
+    for bits in 0..8 {
+        let a = (prefix << 3) | bits;
+
+        if run(a)? == prog[remaining - 1..] {
+            if let Some(found) = search(prog, remaining - 1, a, run)? {
+                return Ok(Some(found));
+            }
+        }
+    }
+
+    Ok(None)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::comp::ThreeBitComp;
+    use aoc_core::read;
+
+    #[test]
+    fn finds_example_quine() {
+        let mut comp = ThreeBitComp::from_str(&read("in/example2"));
+        let prog = comp.prog.clone();
+
+        assert_eq!(
+            find_quine(&prog, |a| comp.output_for(a, 1000)),
+            Ok(117440)
+        );
+    }
+
+    #[test]
+    fn rejects_other_shapes() {
+        // adv 1 instead of adv 3
+        assert!(check_shape(&[0, 1, 5, 4, 3, 0]).is_err());
+        // no adv at all
+        assert!(check_shape(&[1, 3, 5, 4, 3, 0]).is_err());
+        // two outputs per iteration
+        assert!(check_shape(&[0, 3, 5, 4, 5, 4, 3, 0]).is_err());
+        assert!(check_shape(&[0, 3, 5, 4, 3, 0]).is_ok());
+    }
+}
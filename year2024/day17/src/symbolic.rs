@@ -0,0 +1,317 @@
+use std::fmt;
+
+use crate::disasm::{decode, Opcode, Operand};
+
+// registers as expressions of A (and of B and C from the previous iteration)
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Expr {
+    Const(u64),
+    A,
+    InitB,
+    InitC,
+    Mod8(Box<Expr>),
+    Shr(Box<Expr>, Box<Expr>),
+    Xor(Vec<Expr>),
+}
+
+impl Expr {
+    fn mod8(self) -> Expr {
+        match self {
+            Expr::Const(n) => Expr::Const(n % 8),
+            Expr::Mod8(_) => self,
+            e => Expr::Mod8(Box::new(e)),
+        }
+    }
+
+    fn shr(self, shift: Expr) -> Expr {
+        match (self, shift) {
+            (e, Expr::Const(0)) => e,
+            (Expr::Const(n), Expr::Const(s)) => {
+                Expr::Const(n.checked_shr(s as u32).unwrap_or(0))
+            }
+            (e, s) => Expr::Shr(Box::new(e), Box::new(s)),
+        }
+    }
+
+    // flattens nested xors, folds constants and cancels out equal terms
+    fn xor(self, other: Expr) -> Expr {
+        let mut terms = vec![];
+        let mut constant = 0;
+
+        for e in [self, other] {
+            match e {
+                Expr::Xor(inner) => terms.extend(inner),
+                e => terms.push(e),
+            }
+        }
+
+        terms.retain(|t| match t {
+            Expr::Const(n) => {
+                constant ^= n;
+                false
+            }
+            _ => true,
+        });
+
+        terms.sort();
+
+        let mut reduced: Vec<Expr> = vec![];
+
+        for term in terms {
+            if reduced.last() == Some(&term) {
+                reduced.pop();
+            } else {
+                reduced.push(term);
+            }
+        }
+
+        if constant != 0 || reduced.is_empty() {
+            reduced.push(Expr::Const(constant));
+        }
+
+        if reduced.len() == 1 {
+            reduced.pop().unwrap()
+        } else {
+            Expr::Xor(reduced)
+        }
+    }
+}
+
+impl fmt::Display for Expr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Expr::Const(n) => write!(f, "{}", n),
+            Expr::A => write!(f, "A"),
+            Expr::InitB => write!(f, "B0"),
+            Expr::InitC => write!(f, "C0"),
+            Expr::Mod8(e) => write!(f, "({} % 8)", e),
+            Expr::Shr(e, s) => write!(f, "({} >> {})", e, s),
+            Expr::Xor(terms) => {
+                let terms: Vec<_> =
+                    terms.iter().map(|t| t.to_string()).collect();
+                write!(f, "({})", terms.join(" ^ "))
+            }
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Iteration {
+    pub a: Expr,
+    pub b: Expr,
+    pub c: Expr,
+    pub out: Vec<Expr>,
+}
+
+// executes the loop body once (up to the final `jnz`) with A as a symbol
+pub fn execute(prog: &[u8]) -> Result<Iteration, String> {
+    let instructions = decode(prog);
+
+    if instructions.len() != prog.len() / 2 {
+        return Err("program contains invalid instructions".to_string());
+    }
+
+    let mut it = Iteration {
+        a: Expr::A,
+        b: Expr::InitB,
+        c: Expr::InitC,
+        out: vec![],
+    };
+
+    for (i, inst) in instructions.iter().enumerate() {
+        let comb = match inst.operand {
+            Operand::Literal(n) => Expr::Const(n as u64),
+            Operand::A => it.a.clone(),
+            Operand::B => it.b.clone(),
+            Operand::C => it.c.clone(),
+            Operand::Reserved => {
+                return Err(format!("reserved operand 7 at {}", inst.addr))
+            }
+            Operand::Label(_) | Operand::Ignored => Expr::Const(0),
+        };
+
+        match inst.opcode {
+            Opcode::Adv => it.a = it.a.clone().shr(comb),
+            Opcode::Bxl => it.b = it.b.clone().xor(comb),
+            Opcode::Bst => it.b = comb.mod8(),
+            Opcode::Jnz if i == instructions.len() - 1 => break,
+            Opcode::Jnz => {
+                return Err(format!(
+                    "jump at {} is not the end of the loop",
+                    inst.addr
+                ))
+            }
+            Opcode::Bxc => it.b = it.b.clone().xor(it.c.clone()),
+            Opcode::Out => it.out.push(comb.mod8()),
+            Opcode::Bdv => it.b = it.a.clone().shr(comb),
+            Opcode::Cdv => it.c = it.a.clone().shr(comb),
+        }
+    }
+
+    Ok(it)
+}
+
+// a single bit as xor of bits of A (`mask`) and a constant
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Bit {
+    mask: u64,
+    constant: bool,
+    unknown: bool,
+}
+
+const ZERO: Bit = Bit {
+    mask: 0,
+    constant: false,
+    unknown: false,
+};
+
+type Bits = [Bit; 64];
+
+fn concrete(bits: &Bits) -> Option<u64> {
+    bits.iter()
+        .enumerate()
+        .try_fold(0, |acc, (i, b)| {
+            if b.mask != 0 || b.unknown {
+                return None;
+            }
+
+            Some(acc | ((b.constant as u64) << i))
+        })
+}
+
+// evaluates the expression bit by bit with the lowest `low_count` bits of A
+// fixed to `low`, shift amounts have to be known after that
+fn eval_bits(expr: &Expr, low: u64, low_count: u32) -> Result<Bits, String> {
+    let mut bits = [ZERO; 64];
+
+    match expr {
+        Expr::Const(n) => {
+            for (i, bit) in bits.iter_mut().enumerate() {
+                bit.constant = (n >> i) & 1 == 1;
+            }
+        }
+        Expr::A => {
+            for (i, bit) in bits.iter_mut().enumerate() {
+                if (i as u32) < low_count {
+                    bit.constant = (low >> i) & 1 == 1;
+                } else {
+                    bit.mask = 1 << i;
+                }
+            }
+        }
+        Expr::InitB | Expr::InitC => {
+            for bit in bits.iter_mut() {
+                bit.unknown = true;
+            }
+        }
+        Expr::Mod8(e) => {
+            let inner = eval_bits(e, low, low_count)?;
+            bits[..3].copy_from_slice(&inner[..3]);
+        }
+        Expr::Shr(e, s) => {
+            let inner = eval_bits(e, low, low_count)?;
+            let shift = concrete(&eval_bits(s, low, low_count)?).ok_or(
+                format!("shift amount {} depends on unknown bits", s),
+            )?;
+
+            for (i, bit) in bits.iter_mut().enumerate() {
+                if let Some(src) = inner.get(i + shift as usize) {
+                    *bit = *src;
+                }
+            }
+        }
+        Expr::Xor(terms) => {
+            for term in terms {
+                let inner = eval_bits(term, low, low_count)?;
+
+                for (bit, other) in bits.iter_mut().zip(inner) {
+                    bit.mask ^= other.mask;
+                    bit.constant ^= other.constant;
+                    bit.unknown |= other.unknown;
+                }
+            }
+        }
+    }
+
+    Ok(bits)
+}
+
+fn describe_digit(bits: &[Bit]) -> String {
+    if bits.iter().any(|b| b.unknown) {
+        return "depends on B or C of the previous iteration".to_string();
+    }
+
+    let constant = bits
+        .iter()
+        .enumerate()
+        .fold(0, |acc, (i, b)| acc | ((b.constant as u8) << i));
+
+    if bits.iter().all(|b| b.mask == 0) {
+        return constant.to_string();
+    }
+
+    // the common case: three consecutive bits of A xor a constant
+    let start = bits[0].mask.trailing_zeros();
+    let consecutive = bits.iter().enumerate().all(|(i, b)| {
+        b.mask == 1u64.checked_shl(start + i as u32).unwrap_or(0)
+    });
+
+    if consecutive {
+        let slice = format!("A[{}..{}]", start, start + 3);
+
+        return match constant {
+            0 => slice,
+            c => format!("{} ^ {}", c, slice),
+        };
+    }
+
+    // otherwise list every bit, highest first
+    bits.iter()
+        .rev()
+        .map(|b| {
+            let mut terms: Vec<_> = (0..64)
+                .filter(|i| b.mask & (1 << i) != 0)
+                .map(|i| format!("a{}", i))
+                .collect();
+
+            if b.constant || terms.is_empty() {
+                terms.push((b.constant as u8).to_string());
+            }
+
+            terms.join("^")
+        })
+        .collect::<Vec<_>>()
+        .join(" | ")
+}
+
+pub fn analyse(prog: &[u8]) -> Result<String, String> {
+    let it = execute(prog)?;
+    let mut report = String::new();
+
+    report.push_str(&format!("A   = {}\n", it.a));
+    report.push_str(&format!("B   = {}\n", it.b));
+    report.push_str(&format!("C   = {}\n", it.c));
+
+    for out in &it.out {
+        report.push_str(&format!("out = {}\n", out));
+    }
+
+    report.push_str(
+        "\noutput by A % 8 (bits high to low if not a slice of A):\n",
+    );
+
+    for low in 0..8 {
+        let digits: Vec<_> = it
+            .out
+            .iter()
+            .map(|out| match eval_bits(out, low, 3) {
+                Ok(bits) => describe_digit(&bits[..3]),
+                Err(e) => e,
+            })
+            .collect();
+
+        report.push_str(&format!("  {}: {}\n", low, digits.join(", ")));
+    }
+
+    Ok(report)
+}
@@ -0,0 +1,191 @@
+use std::cmp::Ordering;
+use std::fmt;
+use std::iter::Sum;
+
+const BASE: u64 = 1_000_000_000_000_000_000;
+
+// unsigned integer of any size, press counts grow by ~2.5x per robot and
+// overflow u128 at around 95 robots. Stored in base 10^18 limbs, least
+// significant first and without leading zero limbs
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Count {
+    limbs: Vec<u64>,
+}
+
+impl Count {
+    pub fn from_u64(n: u64) -> Count {
+        let mut count = Count {
+            limbs: vec![n % BASE, n / BASE],
+        };
+
+        count.trim();
+        count
+    }
+
+    pub fn add(&self, other: &Count) -> Count {
+        let len = self.limbs.len().max(other.limbs.len());
+        let mut limbs = Vec::with_capacity(len + 1);
+        let mut carry = 0;
+
+        for i in 0..len {
+            let sum = self.limbs.get(i).unwrap_or(&0)
+                + other.limbs.get(i).unwrap_or(&0)
+                + carry;
+
+            limbs.push(sum % BASE);
+            carry = sum / BASE;
+        }
+
+        limbs.push(carry);
+
+        let mut count = Count { limbs };
+        count.trim();
+        count
+    }
+
+    pub fn mul(&self, n: u64) -> Count {
+        let mut limbs = Vec::with_capacity(self.limbs.len() + 2);
+        let mut carry = 0u128;
+
+        for limb in &self.limbs {
+            let product = *limb as u128 * n as u128 + carry;
+
+            limbs.push((product % BASE as u128) as u64);
+            carry = product / BASE as u128;
+        }
+
+        while carry > 0 {
+            limbs.push((carry % BASE as u128) as u64);
+            carry /= BASE as u128;
+        }
+
+        let mut count = Count { limbs };
+        count.trim();
+        count
+    }
+
+    fn trim(&mut self) {
+        while self.limbs.last() == Some(&0) {
+            self.limbs.pop();
+        }
+    }
+}
+
+impl Ord for Count {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.limbs
+            .len()
+            .cmp(&other.limbs.len())
+            .then_with(|| {
+                self.limbs
+                    .iter()
+                    .rev()
+                    .cmp(other.limbs.iter().rev())
+            })
+    }
+}
+
+impl PartialOrd for Count {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Sum for Count {
+    fn sum<I: Iterator<Item = Count>>(iter: I) -> Count {
+        iter.fold(Count::default(), |acc, c| acc.add(&c))
+    }
+}
+
+impl fmt::Display for Count {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Some(last) = self.limbs.last() else {
+            return write!(f, "0");
+        };
+
+        write!(f, "{}", last)?;
+
+        for limb in self.limbs.iter().rev().skip(1) {
+            write!(f, "{:018}", limb)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 10^18 * high + low
+    fn limbs(high: u64, low: u64) -> Count {
+        Count::from_u64(high)
+            .mul(BASE)
+            .add(&Count::from_u64(low))
+    }
+
+    #[test]
+    fn zero() {
+        assert_eq!(Count::from_u64(0), Count::default());
+        assert_eq!(Count::from_u64(0).to_string(), "0");
+        assert_eq!(Count::from_u64(0).mul(7).to_string(), "0");
+        assert_eq!(Count::from_u64(5).mul(0), Count::default());
+    }
+
+    #[test]
+    fn add_carries() {
+        let max = Count::from_u64(BASE - 1);
+
+        assert_eq!(max.add(&Count::from_u64(1)), limbs(1, 0));
+        assert_eq!(
+            limbs(BASE - 1, BASE - 1)
+                .add(&Count::from_u64(1))
+                .to_string(),
+            format!("1{}", "0".repeat(36))
+        );
+        assert_eq!(
+            Count::from_u64(u64::MAX)
+                .add(&Count::from_u64(u64::MAX))
+                .to_string(),
+            (u64::MAX as u128 * 2).to_string()
+        );
+    }
+
+    #[test]
+    fn mul_carries() {
+        assert_eq!(
+            Count::from_u64(u64::MAX)
+                .mul(u64::MAX)
+                .to_string(),
+            (u64::MAX as u128 * u64::MAX as u128).to_string()
+        );
+        assert_eq!(
+            limbs(BASE - 1, BASE - 1).mul(10).to_string(),
+            format!("{}0", "9".repeat(36))
+        );
+    }
+
+    #[test]
+    fn cmp_limb_counts() {
+        let small = Count::from_u64(BASE - 1);
+        let large = limbs(1, 0);
+
+        assert!(small < large);
+        assert!(large > small);
+        assert!(Count::default() < Count::from_u64(1));
+        assert!(limbs(2, 0) > limbs(1, BASE - 1));
+        assert_eq!(limbs(1, 5).cmp(&limbs(1, 5)), Ordering::Equal);
+    }
+
+    #[test]
+    fn display_inner_zero_limbs() {
+        assert_eq!(limbs(1, 1).to_string(), "1000000000000000001");
+        assert_eq!(
+            limbs(1, 0)
+                .mul(BASE)
+                .add(&Count::from_u64(7))
+                .to_string(),
+            format!("1{}7", "0".repeat(35))
+        );
+    }
+}
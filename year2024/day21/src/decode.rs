@@ -0,0 +1,95 @@
+use std::fmt;
+
+use aoc_core::spatial::Point;
+
+use crate::keypad::KeyPad;
+
+// `press` is the index into the presses by hand, `pad` the pad the
+// offending robot arm (or key) is on
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DecodeError {
+    UnknownKey { press: usize, key: char },
+    NotAMove { press: usize, pad: usize, key: char },
+    Gap { press: usize, pad: usize, at: Point },
+}
+
+impl fmt::Display for DecodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DecodeError::UnknownKey { press, key } => {
+                write!(
+                    f,
+                    "press {}: no key {} on the last pad",
+                    press, key
+                )
+            }
+            DecodeError::NotAMove { press, pad, key } => {
+                write!(
+                    f,
+                    "press {}: {} on pad {} is not a direction",
+                    press, key, pad
+                )
+            }
+            DecodeError::Gap { press, pad, at } => {
+                write!(
+                    f,
+                    "press {}: arm on pad {} hovers over the gap at ({}, {})",
+                    press, pad, at.x, at.y
+                )
+            }
+        }
+    }
+}
+
+// replays presses by hand on the last pad through the robot chain and
+// returns what ends up being typed on the first one
+pub fn decode(pads: &[&KeyPad], presses: &str) -> Result<String, DecodeError> {
+    let last = pads.len() - 1;
+    let mut arms: Vec<_> = pads.iter().map(|p| p.position('A')).collect();
+    let mut typed = String::new();
+
+    for (press, key) in presses.chars().enumerate() {
+        if !pads[last].has_key(key) {
+            return Err(DecodeError::UnknownKey { press, key });
+        }
+
+        let mut pad = last;
+        let mut key = key;
+
+        // an `A` presses the key under the next arm, which may be an `A`
+        // again and so on, a direction only moves the next arm
+        while pad > 0 {
+            let robot = pad - 1;
+
+            if key == 'A' {
+                key = pads[robot].key_at(&arms[robot]).unwrap();
+                pad = robot;
+                continue;
+            }
+
+            let step = KeyPad::step(key).ok_or(DecodeError::NotAMove {
+                press,
+                pad,
+                key,
+            })?;
+            let at = arms[robot].add(&step);
+
+            if pads[robot].key_at(&at).is_none() {
+                return Err(DecodeError::Gap {
+                    press,
+                    pad: robot,
+                    at,
+                });
+            }
+
+            arms[robot] = at;
+            break;
+        }
+
+        if pad == 0 {
+            typed.push(key);
+        }
+    }
+
+    Ok(typed)
+}
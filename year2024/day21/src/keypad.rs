@@ -0,0 +1,130 @@
+use std::collections::HashMap;
+
+use aoc_core::spatial::Point;
+
+// the keys a directional pad needs to steer the robot at the next pad
+pub const MOVES: [char; 5] = ['^', 'v', '<', '>', 'A'];
+
+const ARROW_PAD: &str = " ^A\n<v>";
+const NUM_PAD: &str = "789\n456\n123\n 0A";
+
+#[derive(Debug)]
+pub struct KeyPad {
+    keys: HashMap<char, Point>,
+}
+
+impl KeyPad {
+    pub fn arrow_pad() -> Self {
+        KeyPad::from_layout(ARROW_PAD)
+    }
+
+    pub fn num_pad() -> Self {
+        KeyPad::from_layout(NUM_PAD)
+    }
+
+    // one row per line and one key per character, spaces are gaps
+    pub fn from_layout(layout: &str) -> Self {
+        let mut keys = HashMap::new();
+
+        for (y, line) in layout.lines().enumerate() {
+            for (x, key) in line.chars().enumerate() {
+                if key == ' ' {
+                    continue;
+                }
+
+                let point = Point {
+                    x: x as i32,
+                    y: y as i32,
+                };
+
+                if keys.insert(key, point).is_some() {
+                    panic!("key {} appears twice in the layout", key);
+                }
+            }
+        }
+
+        KeyPad { keys }
+    }
+
+    pub fn has_key(&self, key: char) -> bool {
+        self.keys.contains_key(&key)
+    }
+
+    pub fn position(&self, key: char) -> Point {
+        *self
+            .keys
+            .get(&key)
+            .unwrap_or_else(|| panic!("no key {} on the pad", key))
+    }
+
+    pub fn key_at(&self, point: &Point) -> Option<char> {
+        self.keys
+            .iter()
+            .find(|(_, p)| *p == point)
+            .map(|(k, _)| *k)
+    }
+
+    fn is_key(&self, point: &Point) -> bool {
+        self.key_at(point).is_some()
+    }
+
+    // the movement of the robot arm for a directional key
+    pub fn step(key: char) -> Option<Point> {
+        match key {
+            '>' => Some(Point { x: 1, y: 0 }),
+            '<' => Some(Point { x: -1, y: 0 }),
+            'v' => Some(Point { x: 0, y: 1 }),
+            '^' => Some(Point { x: 0, y: -1 }),
+            _ => None,
+        }
+    }
+
+    // all shortest move sequences (each followed by `A`) from one key to
+    // another which never move over a gap
+    pub fn paths(&self, from: char, to: char) -> Vec<String> {
+        let mut paths = vec![];
+        let mut path = String::new();
+
+        self.collect_paths(
+            self.position(from),
+            self.position(to),
+            &mut path,
+            &mut paths,
+        );
+
+        paths
+    }
+
+    fn collect_paths(
+        &self,
+        pos: Point,
+        target: Point,
+        path: &mut String,
+        paths: &mut Vec<String>,
+    ) {
+        if !self.is_key(&pos) {
+            return;
+        }
+
+        if pos == target {
+            paths.push(format!("{}A", path));
+            return;
+        }
+
+        let dx = (target.x - pos.x).signum();
+        let dy = (target.y - pos.y).signum();
+
+        let moves =
+            [(dx > 0, '>'), (dx < 0, '<'), (dy > 0, 'v'), (dy < 0, '^')];
+
+        for (possible, key) in moves {
+            if possible {
+                let step = KeyPad::step(key).unwrap();
+
+                path.push(key);
+                self.collect_paths(pos.add(&step), target, path, paths);
+                path.pop();
+            }
+        }
+    }
+}
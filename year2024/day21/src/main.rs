@@ -0,0 +1,168 @@
+mod count;
+mod decode;
+mod keypad;
+mod presses;
+
+use std::env;
+
+use aoc_core::parse::sections;
+use aoc_core::solution::{run, Solution};
+use aoc_core::{end_measure, read, start_measure};
+use count::Count;
+use decode::decode;
+use keypad::KeyPad;
+use presses::Chain;
+
+fn complexity(input: &str, chain: &mut Chain) -> Count {
+    input
+        .lines()
+        .map(|code| {
+            let code = code.trim();
+            let numeric_part: u64 = code[..code.len() - 1].parse().unwrap();
+
+            chain.presses(code).mul(numeric_part)
+        })
+        .sum()
+}
+
+// the layouts are separated by blank lines, starting with the pad the code is
+// typed on and ending with the one pressed by hand
+fn custom_chain(layouts: &str, codes: &[String]) {
+    let pads: Vec<_> = sections(layouts)
+        .into_iter()
+        .map(KeyPad::from_layout)
+        .collect();
+
+    let mut chain = match Chain::new(pads.iter().collect()) {
+        Ok(chain) => chain,
+        Err(e) => {
+            println!("invalid chain: {}", e);
+            return;
+        }
+    };
+
+    for code in codes.iter().map(|c| c.trim()) {
+        match code.chars().find(|k| !pads[0].has_key(*k)) {
+            Some(key) => println!("{}: no key {} on the first pad", code, key),
+            None => println!("{}: {}", code, chain.presses(code)),
+        }
+    }
+}
+
+// generates the presses for every code, replays them through the chain and
+// checks that they type the code again with the minimal number of presses
+fn verify(input: &str, chain: &mut Chain) {
+    for code in input.lines().map(str::trim) {
+        let presses = chain.press_sequence(code);
+        let minimal = chain.presses(code);
+
+        match decode(chain.pads(), &presses) {
+            Ok(typed) if typed != code => {
+                println!("{}: presses type {} instead", code, typed)
+            }
+            Ok(_) if Count::from_u64(presses.len() as u64) != minimal => {
+                println!(
+                    "{}: {} presses instead of {}",
+                    code,
+                    presses.len(),
+                    minimal
+                )
+            }
+            Ok(_) => println!("{}: ok ({} presses)", code, presses.len()),
+            Err(e) => println!("{}: {}", code, e),
+        }
+    }
+}
+
+fn robot_complexity(input: &str, robots: usize) -> Count {
+    let num_pad = KeyPad::num_pad();
+    let arrow_pad = KeyPad::arrow_pad();
+
+    let mut chain = Chain::robots(&num_pad, &arrow_pad, robots);
+
+    complexity(input, &mut chain)
+}
+
+struct Day;
+
+impl Solution for Day {
+    type Input = String;
+
+    fn parse(input: &str) -> Self::Input {
+        input.trim().to_string()
+    }
+
+    fn part1(codes: &Self::Input) -> String {
+        robot_complexity(codes, 2).to_string()
+    }
+
+    fn part2(codes: &Self::Input) -> String {
+        robot_complexity(codes, 25).to_string()
+    }
+}
+
+fn main() {
+    let mes = start_measure();
+    let input = read("in/input");
+
+    let num_pad = KeyPad::num_pad();
+    let arrow_pad = KeyPad::arrow_pad();
+
+    let args: Vec<String> = env::args().collect();
+
+    // e.g. `cargo run --release -- chain in/hex-chain 1F0A BEEFA`
+    if args.get(1).map(String::as_str) == Some("chain") {
+        let layouts = read(args.get(2).expect("no layout file given"));
+        let codes = if args.len() > 3 {
+            args[3..].to_vec()
+        } else {
+            input.lines().map(str::to_string).collect()
+        };
+
+        custom_chain(&layouts, &codes);
+        end_measure(mes);
+        return;
+    }
+
+    // e.g. `cargo run --release -- decode "<vA<AA>>^A..." [robots]`
+    if let Some(mode @ ("decode" | "verify")) = args.get(1).map(String::as_str)
+    {
+        let robots = match mode {
+            "decode" => args.get(3),
+            _ => args.get(2),
+        };
+        let robots = robots
+            .map(|n| n.parse().expect("robot count is not a number"))
+            .unwrap_or(2);
+
+        let mut chain = Chain::robots(&num_pad, &arrow_pad, robots);
+
+        if mode == "verify" {
+            verify(&input, &mut chain);
+        } else {
+            let presses = args.get(2).expect("no presses given");
+
+            match decode(chain.pads(), presses) {
+                Ok(typed) => println!("typed: {}", typed),
+                Err(e) => println!("invalid presses: {}", e),
+            }
+        }
+
+        end_measure(mes);
+        return;
+    }
+
+    // e.g. `cargo run --release -- 100` for a longer chain of robots
+    if let Some(robots) = args.get(1).and_then(|n| n.parse().ok()) {
+        println!(
+            "complexity with {} robots: {}",
+            robots,
+            robot_complexity(&input, robots)
+        );
+
+        end_measure(mes);
+        return;
+    }
+
+    run::<Day>("in/input");
+}
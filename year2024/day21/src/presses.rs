@@ -0,0 +1,125 @@
+use std::collections::HashMap;
+
+use crate::count::Count;
+use crate::keypad::{KeyPad, MOVES};
+
+// the code is typed on `pads[0]`, every further pad controls the robot in
+// front of it and the last one is pressed by hand
+pub struct Chain<'a> {
+    pads: Vec<&'a KeyPad>,
+    memo: HashMap<(usize, char, char), Count>,
+}
+
+impl<'a> Chain<'a> {
+    // pads can be of any layout, but every pad has to have an `A` to start
+    // on and each pad after the first has to be able to steer a robot
+    pub fn new(pads: Vec<&'a KeyPad>) -> Result<Self, String> {
+        if pads.is_empty() {
+            return Err("chain has no pads".to_string());
+        }
+
+        for (i, pad) in pads.iter().enumerate() {
+            if !pad.has_key('A') {
+                return Err(format!("pad {} has no A key", i));
+            }
+
+            if i > 0 {
+                if let Some(key) = MOVES.iter().find(|k| !pad.has_key(**k)) {
+                    return Err(format!(
+                        "pad {} cannot steer a robot, it has no {} key",
+                        i, key
+                    ));
+                }
+            }
+        }
+
+        Ok(Chain {
+            pads,
+            memo: HashMap::new(),
+        })
+    }
+
+    // the numeric pad, `robots` directional pads in between and ours
+    pub fn robots(
+        num_pad: &'a KeyPad,
+        arrow_pad: &'a KeyPad,
+        robots: usize,
+    ) -> Self {
+        let mut pads = vec![num_pad];
+        pads.extend(vec![arrow_pad; robots + 1]);
+
+        Chain::new(pads).expect("built-in pads form a valid chain")
+    }
+
+    pub fn pads(&self) -> &[&'a KeyPad] {
+        &self.pads
+    }
+
+    // minimal number of presses by hand to type `code`
+    pub fn presses(&mut self, code: &str) -> Count {
+        self.sequence(0, code)
+    }
+
+    // one of the shortest sequences of presses by hand for `code`, its length
+    // grows exponentially so this is only feasible for short chains
+    pub fn press_sequence(&mut self, code: &str) -> String {
+        self.build(0, code)
+    }
+
+    fn build(&mut self, level: usize, sequence: &str) -> String {
+        if level == self.pads.len() - 1 {
+            return sequence.to_string();
+        }
+
+        let mut from = 'A';
+        let mut presses = String::new();
+
+        for to in sequence.chars() {
+            let best = self.pads[level]
+                .paths(from, to)
+                .into_iter()
+                .min_by_key(|path| self.sequence(level + 1, path))
+                .unwrap_or_else(|| panic!("no path from {} to {}", from, to));
+
+            presses.push_str(&self.build(level + 1, &best));
+            from = to;
+        }
+
+        presses
+    }
+
+    // every sequence starts and ends on `A`, so pairs can be solved alone
+    fn sequence(&mut self, level: usize, sequence: &str) -> Count {
+        let mut from = 'A';
+        let mut sum = Count::default();
+
+        for to in sequence.chars() {
+            sum = sum.add(&self.cost(level, from, to));
+            from = to;
+        }
+
+        sum
+    }
+
+    // presses by hand to move from `from` to `to` on `pads[level]` and press it
+    fn cost(&mut self, level: usize, from: char, to: char) -> Count {
+        if level == self.pads.len() - 1 {
+            return Count::from_u64(1);
+        }
+
+        if let Some(count) = self.memo.get(&(level, from, to)) {
+            return count.clone();
+        }
+
+        let count = self.pads[level]
+            .paths(from, to)
+            .iter()
+            .map(|path| self.sequence(level + 1, path))
+            .min()
+            .unwrap_or_else(|| panic!("no path from {} to {}", from, to));
+
+        self.memo.insert((level, from, to), count.clone());
+
+        count
+    }
+}
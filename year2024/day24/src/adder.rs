@@ -0,0 +1,320 @@
+use std::collections::HashMap;
+use std::fmt;
+
+use crate::circuit::{Circuit, Op};
+
+// the canonical full adder for bit `i` with carry `c`:
+//   s = x XOR y, z = s XOR c, a = x AND y, b = s AND c, c' = a OR b
+// bit 0 is a half adder and the last z is the final carry
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StructureError {
+    pub bit: usize,
+    pub reason: String,
+}
+
+impl fmt::Display for StructureError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "bit {}: {}", self.bit, self.reason)
+    }
+}
+
+// gates by their inputs, which stay the same when outputs are swapped
+struct Lookup<'a> {
+    circuit: &'a Circuit,
+    gates: &'a HashMap<(Op, usize, usize), usize>,
+}
+
+fn index_gates(circuit: &Circuit) -> HashMap<(Op, usize, usize), usize> {
+    circuit
+        .gates
+        .iter()
+        .enumerate()
+        .map(|(i, g)| ((g.op, g.a.min(g.b), g.a.max(g.b)), i))
+        .collect()
+}
+
+impl Lookup<'_> {
+    // output wire of the gate `a op b`
+    fn find(&self, op: Op, a: usize, b: usize) -> Option<usize> {
+        self.gates
+            .get(&(op, a.min(b), a.max(b)))
+            .map(|g| self.circuit.gates[*g].out)
+    }
+
+    fn wire(&self, name: &str) -> Option<usize> {
+        self.circuit.ids.get(name).copied()
+    }
+
+    fn name(&self, wire: usize) -> &str {
+        &self.circuit.wires[wire]
+    }
+}
+
+pub fn verify(circuit: &Circuit) -> Result<(), StructureError> {
+    verify_with(circuit, &index_gates(circuit))
+}
+
+fn verify_with(
+    circuit: &Circuit,
+    gates: &HashMap<(Op, usize, usize), usize>,
+) -> Result<(), StructureError> {
+    let lookup = Lookup { circuit, gates };
+    let bits = circuit
+        .wires
+        .iter()
+        .filter(|w| w.starts_with('x'))
+        .count();
+    let mut carry = None;
+
+    for bit in 0..bits {
+        carry = Some(
+            verify_bit(&lookup, bit, carry)
+                .map_err(|reason| StructureError { bit, reason })?,
+        );
+    }
+
+    let last_z = format!("z{:02}", bits);
+    let carry = carry.unwrap();
+
+    if lookup.name(carry) != last_z {
+        return Err(StructureError {
+            bit: bits,
+            reason: format!(
+                "final carry is {} instead of {}",
+                lookup.name(carry),
+                last_z
+            ),
+        });
+    }
+
+    Ok(())
+}
+
+// returns the carry out wire of the bit
+fn verify_bit(
+    lookup: &Lookup,
+    bit: usize,
+    carry: Option<usize>,
+) -> Result<usize, String> {
+    let wire = |name: String| {
+        lookup
+            .wire(&name)
+            .ok_or(format!("no wire {}", name))
+    };
+    let gate = |op: Op, a: usize, b: usize| {
+        lookup.find(op, a, b).ok_or(format!(
+            "no gate {} {} {}",
+            lookup.name(a),
+            op,
+            lookup.name(b)
+        ))
+    };
+
+    let x = wire(format!("x{:02}", bit))?;
+    let y = wire(format!("y{:02}", bit))?;
+    let z = format!("z{:02}", bit);
+
+    let sum = gate(Op::Xor, x, y)?;
+    let and = gate(Op::And, x, y)?;
+
+    let Some(carry) = carry else {
+        if lookup.name(sum) != z {
+            return Err(format!(
+                "x XOR y drives {} instead of {}",
+                lookup.name(sum),
+                z
+            ));
+        }
+
+        return Ok(and);
+    };
+
+    let out = gate(Op::Xor, sum, carry)?;
+
+    if lookup.name(out) != z {
+        return Err(format!(
+            "sum XOR carry drives {} instead of {}",
+            lookup.name(out),
+            z
+        ));
+    }
+
+    let carry_and = gate(Op::And, sum, carry)?;
+    let carry_out = gate(Op::Or, and, carry_and)?;
+
+    for (role, w) in [("x AND y", and), ("sum AND carry", carry_and)] {
+        if lookup.name(w).starts_with('z') {
+            return Err(format!("{} drives {}", role, lookup.name(w)));
+        }
+    }
+
+    Ok(carry_out)
+}
+
+pub fn swap_outputs(circuit: &mut Circuit, g1: usize, g2: usize) {
+    let out = circuit.gates[g1].out;
+    circuit.gates[g1].out = circuit.gates[g2].out;
+    circuit.gates[g2].out = out;
+}
+
+// finds the smallest set of output swaps (up to `max_swaps`) after which the
+// structure verifies and the circuit adds all `cases` correctly. Every swap
+// has to move the first broken bit further up, which keeps the search small
+pub fn find_swaps(
+    circuit: &Circuit,
+    max_swaps: usize,
+    cases: &[(u64, u64)],
+) -> Option<Vec<(String, String)>> {
+    let gates = index_gates(circuit);
+
+    for depth in 0..=max_swaps {
+        let mut circuit = circuit.clone();
+        let mut swaps = vec![];
+
+        if search(&mut circuit, &gates, depth, cases, &mut swaps) {
+            return Some(
+                swaps
+                    .into_iter()
+                    .map(|(a, b)| {
+                        (circuit.wires[a].clone(), circuit.wires[b].clone())
+                    })
+                    .collect(),
+            );
+        }
+    }
+
+    None
+}
+
+fn search(
+    circuit: &mut Circuit,
+    gates: &HashMap<(Op, usize, usize), usize>,
+    depth: usize,
+    cases: &[(u64, u64)],
+    swaps: &mut Vec<(usize, usize)>,
+) -> bool {
+    let broken = match verify_with(circuit, gates) {
+        Ok(()) => return adds_correctly(circuit, cases),
+        Err(e) => e.bit,
+    };
+
+    if depth == 0 {
+        return false;
+    }
+
+    for g1 in 0..circuit.gates.len() {
+        for g2 in g1 + 1..circuit.gates.len() {
+            swap_outputs(circuit, g1, g2);
+
+            let advances = match verify_with(circuit, gates) {
+                Ok(()) => true,
+                Err(e) => e.bit > broken,
+            };
+
+            if advances && circuit.topological_order().is_ok() {
+                swaps.push((circuit.gates[g1].out, circuit.gates[g2].out));
+
+                if search(circuit, gates, depth - 1, cases, swaps) {
+                    return true;
+                }
+
+                swaps.pop();
+            }
+
+            swap_outputs(circuit, g1, g2);
+        }
+    }
+
+    false
+}
+
+pub fn add(circuit: &Circuit, order: &[usize], x: u64, y: u64) -> u64 {
+    let mut initial = circuit.initial.clone();
+
+    for (prefix, value) in [('x', x), ('y', y)] {
+        for (i, wire) in circuit.bus(prefix).into_iter().enumerate() {
+            initial[wire] = Some((value >> i) & 1 == 1);
+        }
+    }
+
+    let values = circuit.evaluate_in(order, &initial);
+
+    circuit.bus_value(&values, 'z')
+}
+
+pub fn adds_correctly(circuit: &Circuit, cases: &[(u64, u64)]) -> bool {
+    let Ok(order) = circuit.topological_order() else {
+        return false;
+    };
+
+    cases
+        .iter()
+        .all(|(x, y)| add(circuit, &order, *x, *y) == x + y)
+}
+
+// single bits, carries through every position and the all ones overflow
+pub fn basic_cases(bits: usize) -> Vec<(u64, u64)> {
+    let ones = (1u64 << bits) - 1;
+    let mut cases = vec![(0, 0), (ones, 1), (ones, ones)];
+
+    for i in 0..bits {
+        cases.push((1 << i, 0));
+        cases.push((0, 1 << i));
+        cases.push((1 << i, 1 << i));
+    }
+
+    cases
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // three bit ripple-carry adder, `{s01}` and `{a01}` name the outputs of
+    // `x01 XOR y01` and `x01 AND y01`
+    const ADDER: &str = "x00: 0\nx01: 0\nx02: 0\ny00: 0\ny01: 0\ny02: 0
+
+x00 XOR y00 -> z00
+x00 AND y00 -> c00
+x01 XOR y01 -> {s01}
+s01 XOR c00 -> z01
+x01 AND y01 -> {a01}
+s01 AND c00 -> b01
+a01 OR b01 -> c01
+x02 XOR y02 -> s02
+s02 XOR c01 -> z02
+x02 AND y02 -> a02
+s02 AND c01 -> b02
+a02 OR b02 -> z03
+";
+
+    fn adder(s01: &str, a01: &str) -> Circuit {
+        Circuit::from_str(&ADDER.replace("{s01}", s01).replace("{a01}", a01))
+    }
+
+    #[test]
+    fn verifies_adder() {
+        let circuit = adder("s01", "a01");
+
+        assert_eq!(verify(&circuit), Ok(()));
+        assert!(adds_correctly(&circuit, &basic_cases(3)));
+    }
+
+    #[test]
+    fn finds_swapped_outputs() {
+        let circuit = adder("a01", "s01");
+
+        assert_eq!(verify(&circuit).map_err(|e| e.bit), Err(1));
+
+        let swaps = find_swaps(&circuit, 2, &basic_cases(3)).unwrap();
+        let swaps: Vec<_> = swaps
+            .into_iter()
+            .map(|(a, b)| (a.clone().min(b.clone()), a.max(b)))
+            .collect();
+
+        assert_eq!(
+            swaps,
+            vec![("a01".to_string(), "s01".to_string())]
+        );
+    }
+}
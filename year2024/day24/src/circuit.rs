@@ -0,0 +1,329 @@
+use std::collections::{HashMap, VecDeque};
+use std::fmt;
+
+use aoc_core::parse::{key_value, sections};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Op {
+    And,
+    Or,
+    Xor,
+}
+
+impl Op {
+    fn from_str(s: &str) -> Op {
+        match s {
+            "AND" => Op::And,
+            "OR" => Op::Or,
+            "XOR" => Op::Xor,
+            _ => panic!("unknown gate {}", s),
+        }
+    }
+
+    pub fn apply(&self, a: bool, b: bool) -> bool {
+        match self {
+            Op::And => a & b,
+            Op::Or => a | b,
+            Op::Xor => a ^ b,
+        }
+    }
+}
+
+impl fmt::Display for Op {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Op::And => write!(f, "AND"),
+            Op::Or => write!(f, "OR"),
+            Op::Xor => write!(f, "XOR"),
+        }
+    }
+}
+
+// wires are referenced by their index into `Circuit::wires`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Gate {
+    pub a: usize,
+    pub b: usize,
+    pub op: Op,
+    pub out: usize,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CircuitError {
+    Cycle(Vec<String>),
+    Undriven(String),
+    MultipleDrivers(String),
+}
+
+impl fmt::Display for CircuitError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CircuitError::Cycle(wires) => {
+                write!(
+                    f,
+                    "combinational cycle through {}",
+                    wires.join(", ")
+                )
+            }
+            CircuitError::Undriven(wire) => {
+                write!(f, "wire {} has no value and no gate", wire)
+            }
+            CircuitError::MultipleDrivers(wire) => {
+                write!(f, "wire {} is driven by more than one gate", wire)
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Circuit {
+    pub wires: Vec<String>,
+    pub ids: HashMap<String, usize>,
+    pub initial: Vec<Option<bool>>,
+    pub gates: Vec<Gate>,
+}
+
+impl Circuit {
+    pub fn from_str(input: &str) -> Self {
+        let sections = sections(input);
+        let mut circuit = Circuit {
+            wires: vec![],
+            ids: HashMap::new(),
+            initial: vec![],
+            gates: vec![],
+        };
+
+        for line in sections[0].lines() {
+            let (wire, value) = key_value(line).unwrap();
+            let id = circuit.wire(wire);
+
+            circuit.initial[id] = Some(value == "1");
+        }
+
+        for line in sections[1].lines() {
+            let split: Vec<_> = line.split_whitespace().collect();
+
+            let gate = Gate {
+                a: circuit.wire(split[0]),
+                op: Op::from_str(split[1]),
+                b: circuit.wire(split[2]),
+                out: circuit.wire(split[4]),
+            };
+
+            circuit.gates.push(gate);
+        }
+
+        circuit
+    }
+
+    fn wire(&mut self, name: &str) -> usize {
+        if let Some(id) = self.ids.get(name) {
+            return *id;
+        }
+
+        self.wires.push(name.to_string());
+        self.initial.push(None);
+        self.ids
+            .insert(name.to_string(), self.wires.len() - 1);
+
+        self.wires.len() - 1
+    }
+
+    // wires starting with `prefix` (e.g. `z`), least significant bit first
+    pub fn bus(&self, prefix: char) -> Vec<usize> {
+        let mut bus: Vec<_> = (0..self.wires.len())
+            .filter(|w| self.wires[*w].starts_with(prefix))
+            .collect();
+
+        bus.sort_by_key(|w| &self.wires[*w]);
+        bus
+    }
+
+    // gate indices in evaluation order (Kahn's algorithm)
+    pub fn topological_order(&self) -> Result<Vec<usize>, CircuitError> {
+        let mut driver = vec![None; self.wires.len()];
+        let mut pending = vec![0; self.gates.len()];
+        let mut readers = vec![vec![]; self.wires.len()];
+
+        for (i, gate) in self.gates.iter().enumerate() {
+            if driver[gate.out].replace(i).is_some() {
+                return Err(CircuitError::MultipleDrivers(
+                    self.wires[gate.out].clone(),
+                ));
+            }
+
+            for input in [gate.a, gate.b] {
+                readers[input].push(i);
+            }
+        }
+
+        let mut queue = VecDeque::new();
+
+        for (i, gate) in self.gates.iter().enumerate() {
+            for input in [gate.a, gate.b] {
+                match driver[input] {
+                    Some(_) => pending[i] += 1,
+                    None if self.initial[input].is_none() => {
+                        return Err(CircuitError::Undriven(
+                            self.wires[input].clone(),
+                        ))
+                    }
+                    None => {}
+                }
+            }
+
+            if pending[i] == 0 {
+                queue.push_back(i);
+            }
+        }
+
+        let mut order = Vec::with_capacity(self.gates.len());
+
+        while let Some(i) = queue.pop_front() {
+            order.push(i);
+
+            for reader in &readers[self.gates[i].out] {
+                pending[*reader] -= 1;
+
+                if pending[*reader] == 0 {
+                    queue.push_back(*reader);
+                }
+            }
+        }
+
+        if let Some(stuck) = (0..self.gates.len()).find(|i| pending[*i] > 0) {
+            return Err(CircuitError::Cycle(
+                self.cycle_from(stuck, &driver, &pending),
+            ));
+        }
+
+        Ok(order)
+    }
+
+    // a gate left pending waits for at least one input driven by another
+    // pending gate. Following those inputs backwards has to run into a gate
+    // again, the gates from its first visit on form the cycle
+    fn cycle_from(
+        &self,
+        stuck: usize,
+        driver: &[Option<usize>],
+        pending: &[usize],
+    ) -> Vec<String> {
+        let mut path = vec![];
+        let mut gate = stuck;
+
+        while !path.contains(&gate) {
+            path.push(gate);
+
+            let g = &self.gates[gate];
+            gate = [g.a, g.b]
+                .into_iter()
+                .filter_map(|input| driver[input])
+                .find(|d| pending[*d] > 0)
+                .unwrap();
+        }
+
+        let start = path.iter().position(|g| *g == gate).unwrap();
+
+        // walked against the signal flow, so reverse for the wire order
+        path[start..]
+            .iter()
+            .rev()
+            .map(|g| self.wires[self.gates[*g].out].clone())
+            .collect()
+    }
+
+    pub fn evaluate(&self) -> Result<Vec<bool>, CircuitError> {
+        let order = self.topological_order()?;
+
+        Ok(self.evaluate_in(&order, &self.initial))
+    }
+
+    // evaluates with the given initial values and a precomputed order
+    pub fn evaluate_in(
+        &self,
+        order: &[usize],
+        initial: &[Option<bool>],
+    ) -> Vec<bool> {
+        let mut values: Vec<_> = initial
+            .iter()
+            .map(|v| v.unwrap_or(false))
+            .collect();
+
+        for i in order {
+            let gate = &self.gates[*i];
+            values[gate.out] = gate.op.apply(values[gate.a], values[gate.b]);
+        }
+
+        values
+    }
+
+    pub fn bus_value(&self, values: &[bool], prefix: char) -> u64 {
+        self.bus(prefix)
+            .iter()
+            .enumerate()
+            .map(|(i, w)| (values[*w] as u64) << i)
+            .sum()
+    }
+
+    pub fn z(&self) -> Result<u64, CircuitError> {
+        Ok(self.bus_value(&self.evaluate()?, 'z'))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const GATES: &str = "x00: 1\nx01: 1\ny00: 0\ny01: 1\n\n";
+
+    #[test]
+    fn evaluates_z() {
+        let circuit = Circuit::from_str(&format!(
+            "{}x00 XOR y00 -> z00\nx01 AND y01 -> z01\nx00 OR y01 -> z02\n",
+            GATES
+        ));
+
+        assert_eq!(circuit.z(), Ok(0b111));
+    }
+
+    #[test]
+    fn finds_only_the_cycle() {
+        // z00 only depends on the cycle between abc and def
+        let circuit = Circuit::from_str(&format!(
+            "{}x00 XOR def -> abc\nabc AND y00 -> def\ndef OR x01 -> z00\n",
+            GATES
+        ));
+
+        let Err(CircuitError::Cycle(mut cycle)) = circuit.evaluate() else {
+            panic!("cycle not detected");
+        };
+        cycle.sort();
+
+        assert_eq!(cycle, vec!["abc", "def"]);
+    }
+
+    #[test]
+    fn undriven_wire() {
+        let circuit =
+            Circuit::from_str(&format!("{}x00 AND abc -> z00\n", GATES));
+
+        assert_eq!(
+            circuit.evaluate(),
+            Err(CircuitError::Undriven("abc".to_string()))
+        );
+    }
+
+    #[test]
+    fn multiple_drivers() {
+        let circuit = Circuit::from_str(&format!(
+            "{}x00 AND y00 -> z00\nx01 OR y01 -> z00\n",
+            GATES
+        ));
+
+        assert_eq!(
+            circuit.evaluate(),
+            Err(CircuitError::MultipleDrivers("z00".to_string()))
+        );
+    }
+}
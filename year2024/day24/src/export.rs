@@ -0,0 +1,247 @@
+use std::collections::{BTreeMap, HashSet};
+
+use crate::circuit::{Circuit, Op};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Role {
+    Input,
+    Carry,
+    Intermediate,
+    Output,
+    Suspect,
+}
+
+impl Role {
+    fn name(&self) -> &'static str {
+        match self {
+            Role::Input => "input",
+            Role::Carry => "carry",
+            Role::Intermediate => "intermediate",
+            Role::Output => "output",
+            Role::Suspect => "suspect",
+        }
+    }
+
+    fn colour(&self) -> &'static str {
+        match self {
+            Role::Input => "lightblue",
+            Role::Carry => "gold",
+            Role::Intermediate => "white",
+            Role::Output => "palegreen",
+            Role::Suspect => "tomato",
+        }
+    }
+}
+
+// every wire is a node, gates are drawn on the wire they drive
+struct Layout {
+    roles: Vec<Role>,
+    ops: Vec<Option<Op>>,
+    clusters: BTreeMap<usize, Vec<usize>>,
+    // wires no bit position reaches, e.g. on a cycle without inputs
+    loose: Vec<usize>,
+    edges: Vec<(usize, usize)>,
+}
+
+fn bus_bit(name: &str) -> Option<usize> {
+    match name.as_bytes().first() {
+        Some(b'x' | b'y' | b'z') => name[1..].parse().ok(),
+        _ => None,
+    }
+}
+
+fn layout(circuit: &Circuit, suspects: &HashSet<String>) -> Layout {
+    let n = circuit.wires.len();
+
+    let mut ops = vec![None; n];
+    let mut bits: Vec<_> = circuit.wires.iter().map(|w| bus_bit(w)).collect();
+
+    for gate in &circuit.gates {
+        ops[gate.out] = Some(gate.op);
+    }
+
+    // a gate belongs to the highest bit position among its inputs, which
+    // puts the carry out of bit i into bit i and the incoming carry into i+1.
+    // Bits only ever grow, so repeating the passes until nothing changes
+    // also settles on broken circuits with cycles
+    let mut changed = true;
+
+    while changed {
+        changed = false;
+
+        for gate in &circuit.gates {
+            let bit = bits[gate.a].max(bits[gate.b]);
+
+            if !circuit.wires[gate.out].starts_with('z') && bit > bits[gate.out]
+            {
+                bits[gate.out] = bit;
+                changed = true;
+            }
+        }
+    }
+
+    let roles = (0..n)
+        .map(|w| {
+            let name = &circuit.wires[w];
+
+            if suspects.contains(name) {
+                Role::Suspect
+            } else if name.starts_with('x') || name.starts_with('y') {
+                Role::Input
+            } else if name.starts_with('z') {
+                Role::Output
+            } else if ops[w] == Some(Op::Or) {
+                Role::Carry
+            } else {
+                Role::Intermediate
+            }
+        })
+        .collect();
+
+    let mut clusters: BTreeMap<usize, Vec<usize>> = BTreeMap::new();
+    let mut loose = vec![];
+
+    for (w, bit) in bits.iter().enumerate() {
+        match bit {
+            Some(bit) => clusters.entry(*bit).or_default().push(w),
+            None => loose.push(w),
+        }
+    }
+
+    for wires in clusters.values_mut() {
+        wires.sort_by_key(|w| &circuit.wires[*w]);
+    }
+
+    let edges = circuit
+        .gates
+        .iter()
+        .flat_map(|g| [(g.a, g.out), (g.b, g.out)])
+        .collect();
+
+    Layout {
+        roles,
+        ops,
+        clusters,
+        loose,
+        edges,
+    }
+}
+
+fn label(circuit: &Circuit, layout: &Layout, wire: usize, sep: &str) -> String {
+    match layout.ops[wire] {
+        Some(op) => format!("{}{}{}", circuit.wires[wire], sep, op),
+        None => circuit.wires[wire].clone(),
+    }
+}
+
+fn dot_node(circuit: &Circuit, layout: &Layout, wire: usize) -> String {
+    format!(
+        "{} [label=\"{}\", fillcolor={}];\n",
+        circuit.wires[wire],
+        label(circuit, layout, wire, "\\n"),
+        layout.roles[wire].colour()
+    )
+}
+
+pub fn to_dot(circuit: &Circuit, suspects: &HashSet<String>) -> String {
+    let layout = layout(circuit, suspects);
+    let mut dot = String::new();
+
+    dot.push_str("digraph circuit {\n");
+    dot.push_str("  rankdir=LR;\n");
+    dot.push_str("  node [shape=box, style=filled];\n");
+
+    for (bit, wires) in &layout.clusters {
+        dot.push_str(&format!("  subgraph cluster_{:02} {{\n", bit));
+        dot.push_str(&format!("    label=\"bit {:02}\";\n", bit));
+
+        for w in wires {
+            dot.push_str(&format!("    {}", dot_node(circuit, &layout, *w)));
+        }
+
+        dot.push_str("  }\n");
+    }
+
+    for w in &layout.loose {
+        dot.push_str(&format!("  {}", dot_node(circuit, &layout, *w)));
+    }
+
+    for (from, to) in &layout.edges {
+        dot.push_str(&format!(
+            "  {} -> {};\n",
+            circuit.wires[*from], circuit.wires[*to]
+        ));
+    }
+
+    dot.push_str("}\n");
+
+    dot
+}
+
+pub fn to_mermaid(circuit: &Circuit, suspects: &HashSet<String>) -> String {
+    let layout = layout(circuit, suspects);
+    let mut mermaid = String::new();
+
+    mermaid.push_str("flowchart LR\n");
+
+    for (bit, wires) in &layout.clusters {
+        mermaid.push_str(&format!(
+            "  subgraph bit{:02} [bit {:02}]\n",
+            bit, bit
+        ));
+
+        for w in wires {
+            mermaid.push_str(&format!(
+                "    {}[\"{}\"]\n",
+                circuit.wires[*w],
+                label(circuit, &layout, *w, "<br/>")
+            ));
+        }
+
+        mermaid.push_str("  end\n");
+    }
+
+    for w in &layout.loose {
+        mermaid.push_str(&format!(
+            "  {}[\"{}\"]\n",
+            circuit.wires[*w],
+            label(circuit, &layout, *w, "<br/>")
+        ));
+    }
+
+    for (from, to) in &layout.edges {
+        mermaid.push_str(&format!(
+            "  {} --> {}\n",
+            circuit.wires[*from], circuit.wires[*to]
+        ));
+    }
+
+    for role in [
+        Role::Input,
+        Role::Carry,
+        Role::Intermediate,
+        Role::Output,
+        Role::Suspect,
+    ] {
+        let wires: Vec<_> = (0..circuit.wires.len())
+            .filter(|w| layout.roles[*w] == role)
+            .map(|w| circuit.wires[w].as_str())
+            .collect();
+
+        mermaid.push_str(&format!(
+            "  classDef {} fill:{}\n",
+            role.name(),
+            role.colour()
+        ));
+
+        if !wires.is_empty() {
+            mermaid.push_str(&format!(
+                "  class {} {}\n",
+                wires.join(","),
+                role.name()
+            ));
+        }
+    }
+
+    mermaid
+}
@@ -1,11 +1,24 @@
+mod adder;
+mod circuit;
+mod export;
+mod test;
+
 use core::fmt;
 use std::{
     cmp::Ordering,
-    collections::VecDeque,
+    collections::{HashSet, VecDeque},
+    env,
     mem::{self},
 };
 
+use adder::{basic_cases, find_swaps, swap_outputs, verify};
 use aoc_core::{end_measure, read, start_measure};
+use circuit::Circuit;
+use export::{to_dot, to_mermaid};
+
+const MAX_SWAPS: usize = 4;
+const RANDOM_SEED: u64 = 24;
+const RANDOM_CASES: usize = 10_000;
 
 #[derive(Debug)]
 struct Instruction {
@@ -123,6 +136,80 @@ impl fmt::Display for Instruction {
 fn main() {
     let mes = start_measure();
     let input = read("in/input");
+
+    let circuit = Circuit::from_str(&input);
+    let mode = env::args().nth(1);
+
+    // `cargo run --release -- test [repaired] [seed]` adds exhaustive, carry
+    // and random cases, `repaired` applies the found swaps first
+    if mode.as_deref() == Some("test") {
+        let args: Vec<_> = env::args().skip(2).collect();
+        let mut circuit = circuit;
+
+        if args.iter().any(|a| a == "repaired") {
+            let Some(swaps) = find_repair(&circuit) else {
+                println!("no repair found within {} swaps", MAX_SWAPS);
+                end_measure(mes);
+                return;
+            };
+
+            repair(&mut circuit, &swaps);
+        }
+
+        let seed = args
+            .iter()
+            .find(|a| *a != "repaired")
+            .map_or(RANDOM_SEED, |s| {
+                s.parse().expect("seed is not a number")
+            });
+
+        run_tests(&circuit, seed);
+        end_measure(mes);
+        return;
+    }
+
+    // e.g. `cargo run --release -- dot | dot -Tsvg > circuit.svg`, the
+    // gates the swap search would repair are marked as suspects, none are
+    // marked when it finds no repair
+    if let Some(format @ ("dot" | "mermaid")) = mode.as_deref() {
+        let swaps = find_repair(&circuit).unwrap_or_else(|| {
+            eprintln!("no repair found within {} swaps", MAX_SWAPS);
+            vec![]
+        });
+        let suspects: HashSet<_> = swapped_wires(&swaps).into_iter().collect();
+
+        match format {
+            "dot" => print!("{}", to_dot(&circuit, &suspects)),
+            _ => print!("{}", to_mermaid(&circuit, &suspects)),
+        }
+
+        return;
+    }
+
+    match circuit.z() {
+        Ok(z) => println!("z: {}", z),
+        Err(e) => println!("cannot evaluate circuit: {}", e),
+    }
+
+    if let Err(e) = verify(&circuit) {
+        println!("not a ripple-carry adder, first break at {}", e);
+    }
+
+    // `cargo run --release -- swaps` searches the swaps that repair the adder
+    // and lists the repaired gates named by their role
+    if mode.as_deref() != Some("swaps") {
+        end_measure(mes);
+        return;
+    }
+
+    let Some(swaps) = find_repair(&circuit) else {
+        println!("no repair found within {} swaps", MAX_SWAPS);
+        end_measure(mes);
+        return;
+    };
+
+    println!("swaps: {}", swapped_wires(&swaps).join(","));
+
     let mut instructions: VecDeque<_> = input
         .split_once("\n\n")
         .unwrap()
@@ -134,11 +221,9 @@ fn main() {
     let mut mapped = vec![];
     let mut loop_count = 0;
 
-    // RESULT
-    swap(&mut instructions, "wbw", "wgb");
-    swap(&mut instructions, "gwh", "z09");
-    swap(&mut instructions, "rcb", "z21");
-    swap(&mut instructions, "jct", "z39");
+    for (from, with) in &swaps {
+        swap(&mut instructions, from, with);
+    }
 
     while instructions.len() > 0 && loop_count < instructions.len() {
         let mut inst = instructions.pop_front().unwrap();
@@ -224,6 +309,95 @@ fn main() {
     end_measure(mes);
 }
 
+fn find_repair(circuit: &Circuit) -> Option<Vec<(String, String)>> {
+    let cases = basic_cases(circuit.bus('x').len());
+
+    find_swaps(circuit, MAX_SWAPS, &cases)
+}
+
+fn repair(circuit: &mut Circuit, swaps: &[(String, String)]) {
+    let gate = |circuit: &Circuit, name: &str| {
+        let wire = circuit.ids[name];
+        circuit
+            .gates
+            .iter()
+            .position(|g| g.out == wire)
+            .unwrap()
+    };
+
+    for (a, b) in swaps {
+        let (g1, g2) = (gate(circuit, a), gate(circuit, b));
+        swap_outputs(circuit, g1, g2);
+    }
+}
+
+fn run_tests(circuit: &Circuit, seed: u64) {
+    let bits = circuit.bus('x').len();
+
+    let suites = [
+        ("exhaustive", test::exhaustive_cases(bits)),
+        ("carry", test::carry_cases(bits)),
+        (
+            "random",
+            test::random_cases(bits, seed, RANDOM_CASES),
+        ),
+    ];
+
+    for (name, cases) in suites {
+        let report = match test::run(circuit, &cases) {
+            Ok(report) => report,
+            Err(e) => {
+                println!("cannot evaluate circuit: {}", e);
+                return;
+            }
+        };
+
+        if report.failures.is_empty() {
+            println!("{}: {} cases, all correct", name, report.cases);
+            continue;
+        }
+
+        println!(
+            "{}: {} cases, {} wrong z bits",
+            name,
+            report.cases,
+            report.failures.len()
+        );
+
+        let suspects = test::suspects(circuit, &report);
+
+        for (bit, (count, (x, y))) in &report.failures {
+            println!(
+                "  z{:02}: wrong in {} cases, e.g. {} + {}",
+                bit, count, x, y
+            );
+
+            if let Some((cone, gates)) = suspects.get(bit) {
+                println!(
+                    "    {} of {} gates in its cone are not used by a \
+                     correct bit:",
+                    gates.len(),
+                    cone
+                );
+
+                for gate in gates {
+                    println!("      {}", gate);
+                }
+            }
+        }
+    }
+}
+
+fn swapped_wires(swaps: &[(String, String)]) -> Vec<String> {
+    let mut wires: Vec<_> = swaps
+        .iter()
+        .flat_map(|(a, b)| [a.clone(), b.clone()])
+        .collect();
+    wires.sort();
+
+    wires
+}
+
 fn swap(instructions: &mut VecDeque<Instruction>, from: &str, with: &str) {
     println!("{from}");
     println!("{with}");
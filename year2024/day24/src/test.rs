@@ -0,0 +1,166 @@
+use std::collections::{BTreeMap, HashSet};
+
+use crate::adder::add;
+use crate::circuit::{Circuit, CircuitError};
+
+// every x/y combination of a window of this many bits is added at every
+// offset, whole buses up to twice the width are tried exhaustively
+const WINDOW: usize = 3;
+
+pub struct Report {
+    pub cases: usize,
+    // failing z bit -> number of failing cases and the first one as (x, y)
+    pub failures: BTreeMap<usize, (usize, (u64, u64))>,
+}
+
+// xorshift64, good enough to spread values over the buses
+struct Random(u64);
+
+impl Random {
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+}
+
+fn mask(bits: usize) -> u64 {
+    if bits >= 64 {
+        u64::MAX
+    } else {
+        (1 << bits) - 1
+    }
+}
+
+pub fn exhaustive_cases(bits: usize) -> Vec<(u64, u64)> {
+    let window = if bits <= WINDOW * 2 { bits } else { WINDOW };
+    let mut cases = vec![];
+
+    for offset in 0..=bits - window {
+        for x in 0..=mask(window) {
+            for y in 0..=mask(window) {
+                cases.push((x << offset, y << offset));
+            }
+        }
+    }
+
+    cases
+}
+
+// runs of ones plus one, the carry has to ripple through the whole run
+pub fn carry_cases(bits: usize) -> Vec<(u64, u64)> {
+    let mut cases = vec![];
+
+    for start in 0..bits {
+        for len in 1..=bits - start {
+            let ones = mask(len) << start;
+
+            cases.push((ones, 1 << start));
+            cases.push((1 << start, ones));
+        }
+    }
+
+    cases
+}
+
+pub fn random_cases(bits: usize, seed: u64, count: usize) -> Vec<(u64, u64)> {
+    // xorshift never leaves zero
+    let mut random = Random(seed.max(1));
+
+    (0..count)
+        .map(|_| {
+            (
+                random.next() & mask(bits),
+                random.next() & mask(bits),
+            )
+        })
+        .collect()
+}
+
+pub fn run(
+    circuit: &Circuit,
+    cases: &[(u64, u64)],
+) -> Result<Report, CircuitError> {
+    let order = circuit.topological_order()?;
+    let mut failures: BTreeMap<usize, (usize, (u64, u64))> = BTreeMap::new();
+
+    for (x, y) in cases {
+        let wrong = add(circuit, &order, *x, *y) ^ (x + y);
+
+        for bit in (0..64).filter(|b| wrong & (1 << b) != 0) {
+            failures.entry(bit).or_insert((0, (*x, *y))).0 += 1;
+        }
+    }
+
+    Ok(Report {
+        cases: cases.len(),
+        failures,
+    })
+}
+
+// indices of the gates `wire` depends on
+pub fn cone(circuit: &Circuit, wire: usize) -> HashSet<usize> {
+    let mut driver = vec![None; circuit.wires.len()];
+
+    for (i, gate) in circuit.gates.iter().enumerate() {
+        driver[gate.out] = Some(i);
+    }
+
+    let mut cone = HashSet::new();
+    let mut stack = vec![wire];
+
+    while let Some(wire) = stack.pop() {
+        if let Some(i) = driver[wire] {
+            if cone.insert(i) {
+                stack.push(circuit.gates[i].a);
+                stack.push(circuit.gates[i].b);
+            }
+        }
+    }
+
+    cone
+}
+
+// the gates in the cone of every failing z bit that no correct z bit uses,
+// the whole cone of a high bit would list most of the adder
+pub fn suspects(
+    circuit: &Circuit,
+    report: &Report,
+) -> BTreeMap<usize, (usize, Vec<String>)> {
+    let z = circuit.bus('z');
+
+    let trusted: HashSet<_> = z
+        .iter()
+        .enumerate()
+        .filter(|(bit, _)| !report.failures.contains_key(bit))
+        .flat_map(|(_, wire)| cone(circuit, *wire))
+        .collect();
+
+    report
+        .failures
+        .keys()
+        .filter_map(|bit| z.get(*bit).map(|wire| (*bit, *wire)))
+        .map(|(bit, wire)| {
+            let cone = cone(circuit, wire);
+            let mut gates: Vec<_> = cone
+                .iter()
+                .filter(|g| !trusted.contains(g))
+                .map(|g| {
+                    let gate = &circuit.gates[*g];
+
+                    format!(
+                        "{} {} {} -> {}",
+                        circuit.wires[gate.a],
+                        gate.op,
+                        circuit.wires[gate.b],
+                        circuit.wires[gate.out]
+                    )
+                })
+                .collect();
+            gates.sort();
+
+            (bit, (cone.len(), gates))
+        })
+        .collect()
+}
@@ -0,0 +1,155 @@
+use aoc_core::grid::Grid;
+use aoc_core::spatial::{Angle, Direction, DirectionalPoint, Point, Rotation};
+
+use crate::PatrolResult;
+
+// position in the tables of the four cardinal directions
+fn dir_index(direction: &Direction) -> usize {
+    *direction as usize / 2
+}
+
+// for every cell and direction the cell the guard stops at in front of the
+// next obstacle, `None` if she walks off the map instead
+pub struct JumpTable {
+    width: i32,
+    height: i32,
+    stops: [Vec<Option<Point>>; 4],
+}
+
+impl JumpTable {
+    pub fn new(grid: &Grid<char>) -> Self {
+        let width = grid.length();
+        let height = grid.height();
+        let cells = (width * height) as usize;
+
+        let mut table = JumpTable {
+            width,
+            height,
+            stops: [vec![], vec![], vec![], vec![]],
+        };
+
+        for direction in Direction::cardinal() {
+            let step = direction.as_point();
+            let mut stops = vec![None; cells];
+
+            // walk each line against the direction, so the stop of the next
+            // cell is always known already
+            let mut points: Vec<_> = (0..height)
+                .flat_map(|y| (0..width).map(move |x| Point { x, y }))
+                .collect();
+            points.sort_by_key(|p| -(p.x * step.x + p.y * step.y));
+
+            for point in points {
+                let next = point.add(&step);
+
+                stops[table.index(&point)] = match grid.get(&next) {
+                    None => None,
+                    Some('#') => Some(point),
+                    Some(_) => stops[table.index(&next)],
+                };
+            }
+
+            table.stops[dir_index(&direction)] = stops;
+        }
+
+        table
+    }
+
+    fn index(&self, point: &Point) -> usize {
+        (point.y * self.width + point.x) as usize
+    }
+
+    fn cells(&self) -> usize {
+        (self.width * self.height) as usize
+    }
+
+    // the stop from the table, moved in front of `extra` if that obstacle
+    // comes first
+    pub fn jump(
+        &self,
+        from: &Point,
+        direction: &Direction,
+        extra: Option<&Point>,
+    ) -> Option<Point> {
+        let stop = self.stops[dir_index(direction)][self.index(from)];
+
+        let Some(extra) = extra else {
+            return stop;
+        };
+
+        let step = direction.as_point();
+        let delta = extra.sub(from);
+        let distance = delta.x * step.x + delta.y * step.y;
+        let on_ray = delta.x * step.y - delta.y * step.x == 0 && distance > 0;
+
+        let before_stop = match stop {
+            Some(stop) => {
+                let delta = stop.sub(from);
+                distance <= delta.x * step.x + delta.y * step.y
+            }
+            None => true,
+        };
+
+        if on_ray && before_stop {
+            Some(extra.sub(&step))
+        } else {
+            stop
+        }
+    }
+
+    // jumps from stop to stop, the guard is in a loop once she stops at the
+    // same cell facing the same way twice
+    pub fn patrol(
+        &self,
+        from: Point,
+        direction: Direction,
+        extra: Option<&Point>,
+    ) -> PatrolResult {
+        self.patrol_with(from, direction, extra, |_| {})
+    }
+
+    // every stop with the direction the guard walked into it, the stop that
+    // closes a loop is not repeated
+    pub fn stops(
+        &self,
+        from: Point,
+        direction: Direction,
+        extra: Option<&Point>,
+    ) -> (Vec<DirectionalPoint>, PatrolResult) {
+        let mut stops = vec![];
+        let result =
+            self.patrol_with(from, direction, extra, |stop| stops.push(stop));
+
+        (stops, result)
+    }
+
+    fn patrol_with(
+        &self,
+        from: Point,
+        direction: Direction,
+        extra: Option<&Point>,
+        mut visit: impl FnMut(DirectionalPoint),
+    ) -> PatrolResult {
+        let mut visited = vec![0u64; (self.cells() * 4).div_ceil(64)];
+        let mut pos = from;
+        let mut direction = direction;
+
+        loop {
+            let Some(stop) = self.jump(&pos, &direction, extra) else {
+                return PatrolResult::Exit;
+            };
+
+            let state = self.index(&stop) * 4 + dir_index(&direction);
+
+            if visited[state / 64] & (1 << (state % 64)) != 0 {
+                return PatrolResult::Loop;
+            }
+
+            visited[state / 64] |= 1 << (state % 64);
+            visit(DirectionalPoint::new(stop, direction));
+
+            pos = stop;
+            direction = direction.rotate(Rotation::Right, Angle::Deg90);
+        }
+    }
+}
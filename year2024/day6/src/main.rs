@@ -0,0 +1,327 @@
+mod jump;
+mod render;
+
+use aoc_core::grid::Grid;
+use aoc_core::solution::{run, Solution};
+use aoc_core::spatial::{
+    Angle, Direction, DirectionalPoint, Point, PointData, Rotation,
+};
+use aoc_core::{end_measure, read, start_measure};
+use jump::JumpTable;
+use render::{render, Style};
+use std::collections::HashSet;
+use std::env;
+use std::thread;
+
+// loops drawn by the render mode unless a count is given
+const RENDERED_LOOPS: usize = 3;
+
+#[derive(Debug, PartialEq)]
+enum PatrolResult {
+    Loop,
+    Exit,
+}
+
+#[derive(Clone)]
+struct PatrolGrid {
+    grid: Grid<char>,
+    visited: Vec<DirectionalPoint>,
+}
+
+impl PatrolGrid {
+    fn from_string(input: &str) -> PatrolGrid {
+        PatrolGrid {
+            grid: Grid::<char>::from_string(input),
+            visited: Vec::new(),
+        }
+    }
+
+    fn get_distinct_visited(&self) -> HashSet<Point> {
+        self.visited.iter().map(|dp| dp.point).collect()
+    }
+
+    fn get_start(&self) -> PointData<'_, char> {
+        self.grid
+            .iter()
+            .find(|p| p.value == &'^')
+            .unwrap()
+    }
+
+    // records every cell with the direction the guard leaves it in, the
+    // patrol without obstructions never loops
+    fn patrol(&mut self, from: Point, direction: Direction) {
+        let mut pos = from;
+        let mut direction = direction;
+
+        loop {
+            self.visited.push(DirectionalPoint {
+                point: pos,
+                direction,
+            });
+
+            loop {
+                match self.grid.move_to(&pos, &direction) {
+                    Some(next) if next.value == &'#' => {
+                        direction =
+                            direction.rotate(Rotation::Right, Angle::Deg90);
+                        self.visited.last_mut().unwrap().direction = direction;
+                    }
+                    Some(next) => {
+                        pos = next.point;
+                        break;
+                    }
+                    None => return,
+                }
+            }
+        }
+    }
+
+    // every cell on the path (except the start) with the state the guard is
+    // in right before she first walks into it, a simulation with an
+    // obstruction there can start from that state
+    fn get_candidates(&self) -> Vec<(Point, DirectionalPoint)> {
+        let mut seen = HashSet::from([self.visited[0].point]);
+
+        self.visited
+            .windows(2)
+            .filter(|w| seen.insert(w[1].point))
+            .map(|w| (w[1].point, w[0]))
+            .collect()
+    }
+}
+
+// every candidate obstruction only exists as the overlay obstacle of its own
+// simulation, so candidates are split into one chunk per thread. The results
+// are in candidate order either way
+fn evaluate(
+    table: &JumpTable,
+    candidates: &[(Point, DirectionalPoint)],
+    threads: usize,
+) -> Vec<(Point, PatrolResult)> {
+    let check = |(to_block, before): &(Point, DirectionalPoint)| {
+        let result =
+            table.patrol(before.point, before.direction, Some(to_block));
+
+        (*to_block, result)
+    };
+
+    if threads <= 1 {
+        return candidates.iter().map(check).collect();
+    }
+
+    let chunk_size = candidates.len().div_ceil(threads).max(1);
+
+    thread::scope(|s| {
+        let handles: Vec<_> = candidates
+            .chunks(chunk_size)
+            .map(|chunk| {
+                s.spawn(move || chunk.iter().map(check).collect::<Vec<_>>())
+            })
+            .collect();
+
+        handles
+            .into_iter()
+            .flat_map(|h| h.join().unwrap())
+            .collect()
+    })
+}
+
+// the patrol without obstructions and the table the obstructions are
+// checked with
+struct Lab {
+    patrol: PatrolGrid,
+    table: JumpTable,
+    start: Point,
+}
+
+impl Lab {
+    fn from_str(input: &str) -> Lab {
+        let mut patrol = PatrolGrid::from_string(input);
+        let start = patrol.get_start().point;
+
+        patrol.patrol(start, Direction::Up);
+
+        Lab {
+            table: JumpTable::new(&patrol.grid),
+            patrol,
+            start,
+        }
+    }
+
+    fn loop_count(&self, threads: usize) -> usize {
+        evaluate(
+            &self.table,
+            &self.patrol.get_candidates(),
+            threads,
+        )
+        .iter()
+        .filter(|(_, r)| r == &PatrolResult::Loop)
+        .count()
+    }
+}
+
+fn available_threads() -> usize {
+    thread::available_parallelism().map_or(1, |n| n.get())
+}
+
+struct Day;
+
+impl Solution for Day {
+    type Input = Lab;
+
+    fn parse(input: &str) -> Self::Input {
+        Lab::from_str(input)
+    }
+
+    fn part1(lab: &Self::Input) -> String {
+        lab.patrol
+            .get_distinct_visited()
+            .len()
+            .to_string()
+    }
+
+    fn part2(lab: &Self::Input) -> String {
+        lab.loop_count(available_threads()).to_string()
+    }
+}
+
+// `cargo run --release -- render [ansi] [x,y | count]` draws the patrol and
+// the loop of one obstruction, or of the first `count` obstructions that
+// cause one
+fn render_loops(lab: &Lab, args: &[String]) {
+    let mut options = args.iter().map(String::as_str).peekable();
+
+    let style = match options.next_if_eq(&"ansi") {
+        Some(_) => Style::Ansi,
+        None => Style::Text,
+    };
+
+    let results = evaluate(
+        &lab.table,
+        &lab.patrol.get_candidates(),
+        available_threads(),
+    );
+
+    let loops: Vec<_> = results
+        .iter()
+        .filter(|(_, r)| r == &PatrolResult::Loop)
+        .map(|(p, _)| *p)
+        .collect();
+
+    let picked = options.peek().and_then(|o| o.split_once(','));
+
+    let selected: Vec<_> = match picked {
+        Some((x, y)) => vec![Point {
+            x: x.parse().expect("x is not a number"),
+            y: y.parse().expect("y is not a number"),
+        }],
+        None => {
+            let count = options.next().map_or(RENDERED_LOOPS, |c| {
+                c.parse().expect("count is not a number")
+            });
+
+            loops.iter().take(count).copied().collect()
+        }
+    };
+
+    println!(
+        "{}",
+        render(
+            &lab.patrol.grid,
+            &lab.table,
+            lab.start,
+            None,
+            style
+        )
+    );
+
+    for to_block in &selected {
+        if lab.patrol.grid.get(to_block) != Some(&'.') {
+            println!(
+                "{},{} cannot be obstructed",
+                to_block.x, to_block.y
+            );
+            continue;
+        }
+
+        let outcome = if loops.contains(to_block) {
+            "loop"
+        } else {
+            "no loop"
+        };
+
+        println!(
+            "obstruction at {},{} ({})",
+            to_block.x, to_block.y, outcome
+        );
+        println!(
+            "{}",
+            render(
+                &lab.patrol.grid,
+                &lab.table,
+                lab.start,
+                Some(to_block),
+                style
+            )
+        );
+    }
+
+    if picked.is_none() && selected.len() < loops.len() {
+        println!(
+            "{} more obstructions cause a loop",
+            loops.len() - selected.len()
+        );
+    }
+}
+
+fn main() {
+    let args: Vec<String> = env::args().collect();
+
+    match args.get(1).map(String::as_str) {
+        Some("render") => {
+            let mes = start_measure();
+
+            render_loops(&Lab::from_str(&read("in/input")), &args[2..]);
+            end_measure(mes);
+        }
+        // `cargo run --release -- 1` checks the candidates on a single thread
+        Some(n) if n.parse::<usize>().is_ok() => {
+            let mes = start_measure();
+            let lab = Lab::from_str(&read("in/input"));
+
+            println!(
+                "visited: {}",
+                lab.patrol.get_distinct_visited().len()
+            );
+            println!("loops: {}", lab.loop_count(n.parse().unwrap()));
+            end_measure(mes);
+        }
+        _ => run::<Day>("in/input"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example() {
+        let lab = Lab::from_str(&read("in/example"));
+
+        assert_eq!(lab.patrol.get_distinct_visited().len(), 41);
+        assert_eq!(lab.loop_count(1), 6);
+        assert_eq!(lab.loop_count(4), 6);
+    }
+
+    // the threaded results come back in candidate order
+    #[test]
+    fn threads_keep_candidate_order() {
+        let lab = Lab::from_str(&read("in/example"));
+        let candidates = lab.patrol.get_candidates();
+
+        assert_eq!(
+            evaluate(&lab.table, &candidates, 1),
+            evaluate(&lab.table, &candidates, 3)
+        );
+    }
+}
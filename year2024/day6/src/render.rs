@@ -0,0 +1,162 @@
+use std::collections::{HashMap, HashSet};
+
+use aoc_core::grid::Grid;
+use aoc_core::spatial::{Angle, Direction, DirectionalPoint, Point, Rotation};
+
+use crate::jump::JumpTable;
+use crate::PatrolResult;
+
+const RESET: &str = "\x1b[0m";
+const PATH: &str = "\x1b[36m";
+const CYCLE: &str = "\x1b[1;33m";
+const OBSTRUCTION: &str = "\x1b[1;31m";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Style {
+    Text,
+    // colours the path, the cycle and the obstruction
+    Ansi,
+}
+
+// cells from `from` to `to` (both included), or to the edge of the map
+fn segment(
+    grid: &Grid<char>,
+    from: Point,
+    to: Option<Point>,
+    direction: Direction,
+) -> Vec<Point> {
+    let step = direction.as_point();
+    let mut cells = vec![from];
+    let mut pos = from;
+
+    while Some(pos) != to {
+        pos = pos.add(&step);
+
+        if grid.get(&pos).is_none() {
+            break;
+        }
+
+        cells.push(pos);
+    }
+
+    cells
+}
+
+// the straight lines of the patrol as (cells, direction), plus the index of
+// the first line of the cycle if she ends up in a loop
+fn lines(
+    grid: &Grid<char>,
+    table: &JumpTable,
+    start: Point,
+    extra: Option<&Point>,
+) -> (Vec<(Vec<Point>, Direction)>, Option<usize>) {
+    let (stops, result) = table.stops(start, Direction::Up, extra);
+
+    let mut lines = vec![];
+    let mut pos = start;
+
+    for stop in &stops {
+        lines.push((
+            segment(grid, pos, Some(stop.point), stop.direction),
+            stop.direction,
+        ));
+        pos = stop.point;
+    }
+
+    let direction = match stops.last() {
+        Some(stop) => stop
+            .direction
+            .rotate(Rotation::Right, Angle::Deg90),
+        None => Direction::Up,
+    };
+
+    if result == PatrolResult::Exit {
+        lines.push((segment(grid, pos, None, direction), direction));
+        return (lines, None);
+    }
+
+    // the stop that closes the loop is the first stop of the cycle
+    let next = table.jump(&pos, &direction, extra).unwrap();
+    let cycle_start = stops
+        .iter()
+        .position(|s| *s == DirectionalPoint::new(next, direction))
+        .unwrap();
+
+    lines.push((
+        segment(grid, pos, Some(next), direction),
+        direction,
+    ));
+
+    (lines, Some(cycle_start + 1))
+}
+
+fn glyph(directions: (bool, bool)) -> char {
+    match directions {
+        (true, true) => '+',
+        (true, false) => '|',
+        (false, true) => '-',
+        (false, false) => '.',
+    }
+}
+
+// draws the path like the puzzle statement does, `O` is the obstruction
+pub fn render(
+    grid: &Grid<char>,
+    table: &JumpTable,
+    start: Point,
+    extra: Option<&Point>,
+    style: Style,
+) -> String {
+    let (lines, cycle_start) = lines(grid, table, start, extra);
+
+    // (vertical, horizontal) movement per cell
+    let mut moves: HashMap<Point, (bool, bool)> = HashMap::new();
+
+    for (cells, direction) in &lines {
+        for cell in cells {
+            let entry = moves.entry(*cell).or_default();
+
+            match direction {
+                Direction::Up | Direction::Down => entry.0 = true,
+                _ => entry.1 = true,
+            }
+        }
+    }
+
+    let cycle: HashSet<_> = match cycle_start {
+        Some(i) => lines[i..]
+            .iter()
+            .flat_map(|(c, _)| c.clone())
+            .collect(),
+        None => HashSet::new(),
+    };
+
+    let mut out = String::new();
+
+    for y in 0..grid.height() {
+        for x in 0..grid.length() {
+            let point = Point { x, y };
+
+            let (c, colour) = if Some(&point) == extra {
+                ('O', OBSTRUCTION)
+            } else if point == start {
+                ('^', PATH)
+            } else if let Some(directions) = moves.get(&point) {
+                let colour = if cycle.contains(&point) { CYCLE } else { PATH };
+                (glyph(*directions), colour)
+            } else {
+                (*grid.get(&point).unwrap(), "")
+            };
+
+            if style == Style::Ansi && !colour.is_empty() {
+                out.push_str(&format!("{}{}{}", colour, c, RESET));
+            } else {
+                out.push(c);
+            }
+        }
+
+        out.push('\n');
+    }
+
+    out
+}
@@ -0,0 +1,416 @@
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap};
+use std::fmt;
+
+// a disk map digit is at most 9, so no file is longer than that. Free spans
+// can be (gaps around empty files are merged), those share the last bucket
+const MAX_SPAN: usize = 9;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Span {
+    pub start: usize,
+    pub len: usize,
+}
+
+// (part of) a file, a fragmented file consists of multiple segments
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Segment {
+    pub id: usize,
+    pub start: usize,
+    pub len: usize,
+}
+
+// `len` blocks of file `id` moved from `from` to `to`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Move {
+    pub id: usize,
+    pub from: usize,
+    pub to: usize,
+    pub len: usize,
+}
+
+impl fmt::Display for Move {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "file {}: {}..{} -> {}..{}",
+            self.id,
+            self.from,
+            self.from + self.len,
+            self.to,
+            self.to + self.len
+        )
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Strategy {
+    // moves single blocks from the end into the leftmost free block
+    Fragmenting,
+    // moves whole files into the leftmost free span they fit in
+    WholeFile,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DiskError {
+    BlockCount {
+        id: usize,
+        expected: usize,
+        found: usize,
+    },
+    MovedRight {
+        id: usize,
+        from: usize,
+        to: usize,
+    },
+    Fragmented {
+        id: usize,
+        segments: usize,
+    },
+    Overlap {
+        block: usize,
+    },
+    OutOfBounds {
+        id: usize,
+    },
+}
+
+impl fmt::Display for DiskError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DiskError::BlockCount {
+                id,
+                expected,
+                found,
+            } => write!(
+                f,
+                "file {} has {} blocks instead of {}",
+                id, found, expected
+            ),
+            DiskError::MovedRight { id, from, to } => {
+                write!(
+                    f,
+                    "file {} moved right from {} to {}",
+                    id, from, to
+                )
+            }
+            DiskError::Fragmented { id, segments } => {
+                write!(
+                    f,
+                    "file {} is split into {} segments",
+                    id, segments
+                )
+            }
+            DiskError::Overlap { block } => {
+                write!(f, "block {} is used by more than one file", block)
+            }
+            DiskError::OutOfBounds { id } => {
+                write!(f, "file {} reaches past the end of the disk", id)
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Disk {
+    pub segments: Vec<Segment>,
+    pub size: usize,
+}
+
+impl Disk {
+    pub fn from_str(input: &str) -> Self {
+        let mut segments = vec![];
+        let mut pos = 0;
+
+        for (i, c) in input.trim().chars().enumerate() {
+            let len = c.to_digit(10).unwrap() as usize;
+
+            if i % 2 == 0 && len > 0 {
+                segments.push(Segment {
+                    id: i / 2,
+                    start: pos,
+                    len,
+                });
+            }
+
+            pos += len;
+        }
+
+        Disk {
+            segments,
+            size: pos,
+        }
+    }
+
+    // gaps between the segments, left to right
+    pub fn free_spans(&self) -> Vec<Span> {
+        let mut segments = self.segments.clone();
+        segments.sort_by_key(|s| s.start);
+
+        let mut spans = vec![];
+        let mut pos = 0;
+
+        for segment in segments {
+            if segment.start > pos {
+                spans.push(Span {
+                    start: pos,
+                    len: segment.start - pos,
+                });
+            }
+
+            pos = segment.start + segment.len;
+        }
+
+        if pos < self.size {
+            spans.push(Span {
+                start: pos,
+                len: self.size - pos,
+            });
+        }
+
+        spans
+    }
+
+    // the compacted disk and every move in the order they were made
+    pub fn compact(&self, strategy: Strategy) -> (Disk, Vec<Move>) {
+        let mut moves = vec![];
+
+        let segments = match strategy {
+            Strategy::Fragmenting => self.compact_blocks(&mut moves),
+            Strategy::WholeFile => self.compact_files(&mut moves),
+        };
+
+        let disk = Disk {
+            segments,
+            size: self.size,
+        };
+
+        (disk, moves)
+    }
+
+    // fills free spans from the left with the last blocks of the rightmost
+    // file, splitting segments where they don't fit
+    fn compact_blocks(&self, moves: &mut Vec<Move>) -> Vec<Segment> {
+        let mut files = self.segments.clone();
+        let mut moved = vec![];
+        let mut last = files.len();
+
+        'spans: for mut span in self.free_spans() {
+            while span.len > 0 {
+                let Some(file) = last.checked_sub(1).map(|i| &mut files[i])
+                else {
+                    break 'spans;
+                };
+
+                if file.start < span.start {
+                    break 'spans;
+                }
+
+                let len = span.len.min(file.len);
+
+                moved.push(Segment {
+                    id: file.id,
+                    start: span.start,
+                    len,
+                });
+
+                file.len -= len;
+
+                moves.push(Move {
+                    id: file.id,
+                    from: file.start + file.len,
+                    to: span.start,
+                    len,
+                });
+
+                span.start += len;
+                span.len -= len;
+
+                if file.len == 0 {
+                    last -= 1;
+                }
+            }
+        }
+
+        files.retain(|f| f.len > 0);
+        files.extend(moved);
+        files
+    }
+
+    // free span starts indexed by span length, each file takes the leftmost
+    // start of all lengths it fits in. The space a file leaves behind is
+    // never used again as all remaining files are left of it
+    fn compact_files(&self, moves: &mut Vec<Move>) -> Vec<Segment> {
+        // (start, len) of the free spans by length
+        let mut free: Vec<BinaryHeap<Reverse<(usize, usize)>>> =
+            vec![BinaryHeap::new(); MAX_SPAN + 1];
+
+        for span in self.free_spans() {
+            free[span.len.min(MAX_SPAN)].push(Reverse((span.start, span.len)));
+        }
+
+        let mut files = self.segments.clone();
+
+        for file in files.iter_mut().rev() {
+            let best = (file.len..=MAX_SPAN)
+                .filter_map(|bucket| free[bucket].peek().map(|s| (s.0, bucket)))
+                .filter(|((start, _), _)| *start < file.start)
+                .min();
+
+            let Some(((start, len), bucket)) = best else {
+                continue;
+            };
+
+            free[bucket].pop();
+
+            if len > file.len {
+                let rest = len - file.len;
+                free[rest.min(MAX_SPAN)]
+                    .push(Reverse((start + file.len, rest)));
+            }
+
+            moves.push(Move {
+                id: file.id,
+                from: file.start,
+                to: start,
+                len: file.len,
+            });
+
+            file.start = start;
+        }
+
+        files
+    }
+
+    // checks the compacted disk against the disk it was compacted from
+    pub fn verify(
+        &self,
+        original: &Disk,
+        strategy: Strategy,
+    ) -> Result<(), DiskError> {
+        let starts: HashMap<_, _> = original
+            .segments
+            .iter()
+            .map(|s| (s.id, s.start))
+            .collect();
+
+        let mut blocks = vec![false; self.size];
+        let mut files: HashMap<usize, (usize, usize)> = HashMap::new();
+
+        for segment in &self.segments {
+            let (id, start) = (segment.id, segment.start);
+
+            if start + segment.len > self.size {
+                return Err(DiskError::OutOfBounds { id });
+            }
+
+            let Some(from) = starts.get(&id).copied() else {
+                return Err(DiskError::BlockCount {
+                    id,
+                    expected: 0,
+                    found: segment.len,
+                });
+            };
+
+            if start > from {
+                return Err(DiskError::MovedRight {
+                    id,
+                    from,
+                    to: start,
+                });
+            }
+
+            let span = &mut blocks[start..start + segment.len];
+
+            if let Some(i) = span.iter().position(|used| *used) {
+                return Err(DiskError::Overlap { block: start + i });
+            }
+
+            span.fill(true);
+
+            let file = files.entry(id).or_default();
+            file.0 += segment.len;
+            file.1 += 1;
+        }
+
+        for segment in &original.segments {
+            let (found, segments) = files
+                .get(&segment.id)
+                .copied()
+                .unwrap_or_default();
+
+            if found != segment.len {
+                return Err(DiskError::BlockCount {
+                    id: segment.id,
+                    expected: segment.len,
+                    found,
+                });
+            }
+
+            if strategy == Strategy::WholeFile && segments > 1 {
+                return Err(DiskError::Fragmented {
+                    id: segment.id,
+                    segments,
+                });
+            }
+        }
+
+        Ok(())
+    }
+
+    pub fn get_checksum(&self) -> usize {
+        self.segments
+            .iter()
+            .map(|s| s.id * (s.len * s.start + s.len * (s.len - 1) / 2))
+            .sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // file 1 is empty, the two gaps around it form one free span of 10
+    #[test]
+    fn empty_file_between_gaps() {
+        let disk = Disk::from_str("15052");
+
+        assert_eq!(
+            disk.free_spans(),
+            vec![Span { start: 1, len: 10 }]
+        );
+
+        let (compacted, moves) = disk.compact(Strategy::WholeFile);
+
+        assert_eq!(
+            compacted.segments,
+            vec![
+                Segment {
+                    id: 0,
+                    start: 0,
+                    len: 1
+                },
+                Segment {
+                    id: 2,
+                    start: 1,
+                    len: 2
+                },
+            ]
+        );
+        assert_eq!(
+            moves,
+            vec![Move {
+                id: 2,
+                from: 11,
+                to: 1,
+                len: 2
+            }]
+        );
+        assert_eq!(
+            compacted.verify(&disk, Strategy::WholeFile),
+            Ok(())
+        );
+        // 0 * 0 + 2 * 1 + 2 * 2
+        assert_eq!(compacted.get_checksum(), 6);
+    }
+}
@@ -0,0 +1,49 @@
+mod disk;
+mod render;
+
+use std::env;
+
+use aoc_core::{end_measure, read, start_measure};
+use disk::{Disk, Strategy};
+use render::{render, render_trace, MAX_BLOCKS};
+
+fn main() {
+    let mes = start_measure();
+
+    let input = read("in/input");
+    let disk = Disk::from_str(&input);
+
+    // `cargo run -- trace` prints every move of the compaction
+    let trace = env::args().nth(1).as_deref() == Some("trace");
+    let small = disk.size <= MAX_BLOCKS;
+
+    if small {
+        println!("{}", render(&disk));
+    }
+
+    for (name, strategy) in [
+        ("fragmenting", Strategy::Fragmenting),
+        ("whole files", Strategy::WholeFile),
+    ] {
+        let (compacted, moves) = disk.compact(strategy);
+
+        if trace && small {
+            for frame in render_trace(&disk, &moves).iter().skip(1) {
+                println!("{}", frame);
+            }
+        } else if trace {
+            for m in &moves {
+                println!("{}", m);
+            }
+        } else if small {
+            println!("{}", render(&compacted));
+        }
+
+        match compacted.verify(&disk, strategy) {
+            Ok(()) => println!("{}: {}", name, compacted.get_checksum()),
+            Err(e) => println!("{}: invalid compaction, {}", name, e),
+        }
+    }
+
+    end_measure(mes)
+}
@@ -0,0 +1,53 @@
+use crate::disk::{Disk, Move};
+
+// larger disks are not rendered
+pub const MAX_BLOCKS: usize = 200;
+
+// one character per file id: 0-9, a-z, A-Z and `#` for any larger id
+fn id_char(id: usize) -> char {
+    match id {
+        0..=9 => (b'0' + id as u8) as char,
+        10..=35 => (b'a' + (id - 10) as u8) as char,
+        36..=61 => (b'A' + (id - 36) as u8) as char,
+        _ => '#',
+    }
+}
+
+fn blocks(disk: &Disk) -> Vec<Option<usize>> {
+    let mut blocks = vec![None; disk.size];
+
+    for segment in &disk.segments {
+        for block in &mut blocks[segment.start..segment.start + segment.len] {
+            *block = Some(segment.id);
+        }
+    }
+
+    blocks
+}
+
+fn line(blocks: &[Option<usize>]) -> String {
+    blocks
+        .iter()
+        .map(|b| b.map(id_char).unwrap_or('.'))
+        .collect()
+}
+
+// e.g. `00...111...2...333.44.5555.6666.777.888899`
+pub fn render(disk: &Disk) -> String {
+    line(&blocks(disk))
+}
+
+// the layout before compaction and after each move
+pub fn render_trace(disk: &Disk, moves: &[Move]) -> Vec<String> {
+    let mut blocks = blocks(disk);
+    let mut frames = vec![line(&blocks)];
+
+    for m in moves {
+        blocks[m.from..m.from + m.len].fill(None);
+        blocks[m.to..m.to + m.len].fill(Some(m.id));
+
+        frames.push(line(&blocks));
+    }
+
+    frames
+}